@@ -19,10 +19,10 @@ macro_rules! bench_binop {
         fn $name(bh: &mut Bencher) {
             const LEN: usize = 1 << 13;
 
-            let mut rng = SmallRng::from_entropy();
+            let mut rng = SmallRng::from_os_rng();
 
-            let elems1: Vec<$t1> = (0..LEN).map(|_| rng.gen::<$t1>()).collect();
-            let elems2: Vec<$t2> = (0..LEN).map(|_| rng.gen::<$t2>()).collect();
+            let elems1: Vec<$t1> = (0..LEN).map(|_| rng.random::<$t1>()).collect();
+            let elems2: Vec<$t2> = (0..LEN).map(|_| rng.random::<$t2>()).collect();
             let mut i = 0;
 
             bh.iter(|| {
@@ -40,9 +40,9 @@ macro_rules! bench_unop {
         fn $name(bh: &mut Bencher) {
             const LEN: usize = 1 << 13;
 
-            let mut rng = SmallRng::from_entropy();
+            let mut rng = SmallRng::from_os_rng();
 
-            let mut elems: Vec<$t> = (0..LEN).map(|_| rng.gen::<$t>()).collect();
+            let mut elems: Vec<$t> = (0..LEN).map(|_| rng.random::<$t>()).collect();
             let mut i = 0;
 
             bh.iter(|| {
@@ -60,9 +60,9 @@ macro_rules! bench_construction {
         fn $name(bh: &mut Bencher) {
             const LEN: usize = 1 << 13;
 
-            let mut rng = SmallRng::from_entropy();
+            let mut rng = SmallRng::from_os_rng();
 
-            $(let $args: Vec<$types> = (0..LEN).map(|_| rng.gen::<$types>()).collect();)*
+            $(let $args: Vec<$types> = (0..LEN).map(|_| rng.random::<$types>()).collect();)*
             let mut i = 0;
 
             bh.iter(|| {
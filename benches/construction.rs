@@ -32,10 +32,10 @@ mod macros;
 fn bench_from_axis_angle<T: Rotation3<Scalar = f32>>(bh: &mut Bencher) {
     const LEN: usize = 1 << 13;
 
-    let mut rng = SmallRng::from_entropy();
+    let mut rng = SmallRng::from_os_rng();
 
-    let axis: Vec<_> = (0..LEN).map(|_| rng.gen::<Vector3<f32>>()).collect();
-    let angle: Vec<_> = (0..LEN).map(|_| rng.gen::<Rad<f32>>()).collect();
+    let axis: Vec<_> = (0..LEN).map(|_| rng.random::<Vector3<f32>>()).collect();
+    let angle: Vec<_> = (0..LEN).map(|_| rng.random::<Rad<f32>>()).collect();
     let mut i = 0;
 
     bh.iter(|| {
@@ -23,7 +23,7 @@ use std::ops::*;
 use num_traits::{cast, Bounded};
 #[cfg(feature = "rand")]
 use rand::{
-    distributions::{uniform::SampleUniform, Distribution, Standard},
+    distr::{uniform::SampleUniform, Distribution, StandardUniform},
     Rng,
 };
 
@@ -113,8 +113,33 @@ where
     }
 }
 
+/// A distribution sampling angles uniformly from the half-open range
+/// `[low, high)`.
+///
+/// For sampling over a full turn, use `StandardUniform` instead.
+#[cfg(feature = "rand")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct UniformAngle<A> {
+    low: A,
+    high: A,
+}
+
+#[cfg(feature = "rand")]
+impl<A: PartialOrd> UniformAngle<A> {
+    /// Create a distribution over `[low, high)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    #[inline]
+    pub fn new(low: A, high: A) -> UniformAngle<A> {
+        assert!(low < high, "UniformAngle::new called with `low >= high`");
+        UniformAngle { low, high }
+    }
+}
+
 macro_rules! impl_angle {
-    ($Angle:ident, $fmt:expr, $full_turn:expr, $hi:expr) => {
+    ($Angle:ident, $unit:expr, $full_turn:expr) => {
         impl<S: BaseFloat> Zero for $Angle<S> {
             #[inline]
             fn zero() -> $Angle<S> {
@@ -256,23 +281,78 @@ macro_rules! impl_angle {
             }
         }
 
+        /// Samples uniformly over a full turn, i.e. `[0, 2π)` for `Rad` and
+        /// `[0, 360)` for `Deg`.
         #[cfg(feature = "rand")]
-        impl<S> Distribution<$Angle<S>> for Standard
-            where Standard: Distribution<S>,
+        impl<S> Distribution<$Angle<S>> for StandardUniform
+            where StandardUniform: Distribution<S>,
                 S: BaseFloat + SampleUniform {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Angle<S> {
-                $Angle(rng.gen_range(cast::<_, S>(-$hi).unwrap() .. cast::<_, S>($hi).unwrap()))
+                $Angle(rng.random_range(S::zero() .. cast::<_, S>($full_turn).unwrap()))
+            }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<S> Distribution<$Angle<S>> for UniformAngle<$Angle<S>>
+            where S: BaseFloat + SampleUniform {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $Angle<S> {
+                $Angle(rng.random_range(self.low.0 .. self.high.0))
             }
         }
 
         impl<S: fmt::Debug> fmt::Debug for $Angle<S> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, $fmt, self.0)
+                write!(f, "{:?}{}", self.0, $unit)
+            }
+        }
+
+        /// Formats the value followed by its unit, respecting the formatter's
+        /// flags such as the precision.
+        impl<S: fmt::Display> fmt::Display for $Angle<S> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)?;
+                f.write_str($unit)
             }
         }
     }
 }
 
-impl_angle!(Rad, "{:?} rad", f64::consts::PI * 2.0, f64::consts::PI);
-impl_angle!(Deg, "{:?}°", 360, 180);
+impl_angle!(Rad, " rad", f64::consts::PI * 2.0);
+impl_angle!(Deg, "°", 360);
+
+/// Shorthand constructors for angles from raw scalars.
+///
+/// ```rust
+/// use cgmath::{AngleExt, Deg, Rad};
+///
+/// assert_eq!(90.0f32.deg(), Deg(90.0));
+/// assert_eq!(0.5f64.rad(), Rad(0.5));
+/// ```
+pub trait AngleExt: Sized {
+    /// Interpret the value as an angle in degrees.
+    fn deg(self) -> Deg<Self>;
+
+    /// Interpret the value as an angle in radians.
+    fn rad(self) -> Rad<Self>;
+}
+
+macro_rules! impl_angle_ext {
+    ($S:ty) => {
+        impl AngleExt for $S {
+            #[inline]
+            fn deg(self) -> Deg<$S> {
+                Deg(self)
+            }
+
+            #[inline]
+            fn rad(self) -> Rad<$S> {
+                Rad(self)
+            }
+        }
+    };
+}
+
+impl_angle_ext!(f32);
+impl_angle_ext!(f64);
@@ -0,0 +1,139 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bounding volumes and other geometric primitives.
+
+use structure::*;
+
+use num::BaseFloat;
+use point::Point3;
+use vector::Vector3;
+
+/// A sphere in 3-dimensional space, given by its center and radius.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Sphere3<S> {
+    /// The center of the sphere.
+    pub center: Point3<S>,
+    /// The radius of the sphere.
+    pub radius: S,
+}
+
+impl<S> Sphere3<S> {
+    /// Construct a new sphere from its center and radius.
+    #[inline]
+    pub const fn new(center: Point3<S>, radius: S) -> Sphere3<S> {
+        Sphere3 { center, radius }
+    }
+}
+
+impl<S: BaseFloat> Sphere3<S> {
+    /// Compute a bounding sphere for a set of points, or `None` if there are
+    /// no points.
+    ///
+    /// This uses [Ritter's algorithm], which runs in linear time and gives a
+    /// sphere that is usually within a few percent of the smallest one.
+    ///
+    /// [Ritter's algorithm]: https://en.wikipedia.org/wiki/Bounding_sphere#Ritter's_bounding_sphere
+    pub fn from_points(points: &[Point3<S>]) -> Option<Sphere3<S>> {
+        let first = *points.first()?;
+        let farthest_from = |p: Point3<S>| {
+            points.iter().cloned().fold(p, |best, q| {
+                if p.distance2(q) > p.distance2(best) {
+                    q
+                } else {
+                    best
+                }
+            })
+        };
+
+        // start with the sphere spanned by two points that are far apart
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let half: S = S::from(0.5f64).unwrap();
+        let mut sphere = Sphere3::new(a.midpoint(b), a.distance(b) * half);
+
+        // grow the sphere to include every point outside of it
+        for &p in points {
+            let dist = sphere.center.distance(p);
+            if dist > sphere.radius {
+                let radius = (sphere.radius + dist) * half;
+                sphere.center += (p - sphere.center) * ((radius - sphere.radius) / dist);
+                sphere.radius = radius;
+            }
+        }
+
+        Some(sphere)
+    }
+
+    /// Test if `point` lies inside the sphere or on its surface.
+    #[inline]
+    pub fn contains(&self, point: Point3<S>) -> bool {
+        self.center.distance2(point) <= self.radius * self.radius
+    }
+
+    /// Test if the sphere overlaps or touches `other`.
+    #[inline]
+    pub fn intersects(&self, other: &Sphere3<S>) -> bool {
+        let radii = self.radius + other.radius;
+        self.center.distance2(other.center) <= radii * radii
+    }
+
+    /// The smallest sphere enclosing both `self` and `other`.
+    pub fn union(&self, other: &Sphere3<S>) -> Sphere3<S> {
+        let dist = self.center.distance(other.center);
+        if dist + other.radius <= self.radius {
+            return *self;
+        }
+        if dist + self.radius <= other.radius {
+            return *other;
+        }
+
+        let half: S = S::from(0.5f64).unwrap();
+        let radius = (dist + self.radius + other.radius) * half;
+        let center = self.center + (other.center - self.center) * ((radius - self.radius) / dist);
+        Sphere3::new(center, radius)
+    }
+}
+
+/// A ray in 3-dimensional space, starting at `origin` and extending along
+/// `direction`.
+///
+/// The direction is not required to be normalized. A point along the ray is
+/// given by `origin + direction * t` for some parameter `t >= 0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray3<S> {
+    /// The point the ray starts from.
+    pub origin: Point3<S>,
+    /// The direction the ray extends in.
+    pub direction: Vector3<S>,
+}
+
+impl<S> Ray3<S> {
+    /// Construct a new ray from its origin and direction.
+    #[inline]
+    pub const fn new(origin: Point3<S>, direction: Vector3<S>) -> Ray3<S> {
+        Ray3 { origin, direction }
+    }
+}
+
+impl<S: BaseFloat> Ray3<S> {
+    /// The point at parameter `t` along the ray.
+    #[inline]
+    pub fn at(&self, t: S) -> Point3<S> {
+        self.origin + self.direction * t
+    }
+}
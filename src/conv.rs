@@ -49,6 +49,31 @@
 //! # }
 //! ` ` `
 
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when converting a slice of the wrong length into a
+/// vector or point with `TryFrom`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SliceLengthError {
+    /// The number of elements the target type has.
+    pub expected: usize,
+    /// The length of the slice.
+    pub found: usize,
+}
+
+impl fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "expected a slice of length {}, found length {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl Error for SliceLengthError {}
+
 /// Force a conversion into a 2-element array.
 #[inline]
 pub fn array2<T, A: Into<[T; 2]>>(value: A) -> [T; 2] {
@@ -16,7 +16,7 @@
 use num_traits::cast;
 #[cfg(feature = "rand")]
 use rand::{
-    distributions::{Distribution, Standard},
+    distr::{Distribution, StandardUniform},
     Rng,
 };
 
@@ -24,10 +24,12 @@ use structure::*;
 
 use angle::Rad;
 use approx;
+use interpolate::clamp;
 #[cfg(feature = "mint")]
 use mint;
 use num::BaseFloat;
 use quaternion::Quaternion;
+use rotation::Rotation3;
 
 /// A set of [Euler angles] representing a rotation in three-dimensional space.
 ///
@@ -103,6 +105,97 @@ impl<A> Euler<A> {
     }
 }
 
+impl<A: Angle> Euler<A> {
+    /// Wrap each angle into the range `[-turn_div_2, turn_div_2)`.
+    ///
+    /// This is done independently for each axis, and does not try to pick
+    /// the canonical one of several sets of angles that describe the same
+    /// rotation.
+    pub fn normalized(self) -> Euler<A> {
+        let wrap = |a: A| (a + A::turn_div_2()).normalize() - A::turn_div_2();
+        Euler::new(wrap(self.x), wrap(self.y), wrap(self.z))
+    }
+
+    /// Clamp each angle into the range given by the matching angles of `min`
+    /// and `max`, e.g. to apply joint limits.
+    ///
+    /// The angles are compared as they are, without normalizing them first.
+    pub fn clamp(self, min: Euler<A>, max: Euler<A>) -> Euler<A> {
+        Euler::new(
+            clamp(self.x, min.x, max.x),
+            clamp(self.y, min.y, max.y),
+            clamp(self.z, min.z, max.z),
+        )
+    }
+
+    /// Linearly interpolate each angle towards the matching angle of `other`,
+    /// going the short way around, e.g. from 350° to 10° through 0°.
+    ///
+    /// The results are wrapped into the range `[-turn_div_2, turn_div_2]`.
+    ///
+    /// Note that this is not equivalent to interpolating the rotations with
+    /// `Quaternion::slerp`. The axes are interpolated independently, so the
+    /// path is not the shortest rotation and can suffer from gimbal lock.
+    pub fn lerp(self, other: Euler<A>, amount: A::Unitless) -> Euler<A> {
+        let lerp = |a: A, b: A| (a + (b - a).normalize_signed() * amount).normalize_signed();
+        Euler::new(
+            lerp(self.x, other.x),
+            lerp(self.y, other.y),
+            lerp(self.z, other.z),
+        )
+    }
+}
+
+impl<A> Euler<A>
+where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    /// Convert the angles to a quaternion, applying the rotations in the
+    /// given order.
+    ///
+    /// `Quaternion::from` uses `EulerOrder::Xyz`.
+    pub fn to_quaternion(self, order: EulerOrder) -> Quaternion<A::Unitless> {
+        let qx = Quaternion::from_angle_x(self.x);
+        let qy = Quaternion::from_angle_y(self.y);
+        let qz = Quaternion::from_angle_z(self.z);
+
+        match order {
+            EulerOrder::Xyz => qx * qy * qz,
+            EulerOrder::Xzy => qx * qz * qy,
+            EulerOrder::Yxz => qy * qx * qz,
+            EulerOrder::Yzx => qy * qz * qx,
+            EulerOrder::Zxy => qz * qx * qy,
+            EulerOrder::Zyx => qz * qy * qx,
+        }
+    }
+}
+
+/// The order in which the three [Tait–Bryan] rotations of a set of Euler
+/// angles are applied.
+///
+/// Each variant lists the axes in the order of the intrinsic rotations, so
+/// `Xyz` rotates first around the _x_ axis, then around the new _y_ axis, and
+/// lastly around the new _z_ axis. This is the order used by `Euler` itself.
+/// In terms of matrices, `Xyz` corresponds to `Rx * Ry * Rz`.
+///
+/// [Tait–Bryan]: https://en.wikipedia.org/wiki/Euler_angles#Tait%E2%80%93Bryan_angles
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EulerOrder {
+    /// Rotate around _x_, then _y_, then _z_.
+    Xyz,
+    /// Rotate around _x_, then _z_, then _y_.
+    Xzy,
+    /// Rotate around _y_, then _x_, then _z_.
+    Yxz,
+    /// Rotate around _y_, then _z_, then _x_.
+    Yzx,
+    /// Rotate around _z_, then _x_, then _y_.
+    Zxy,
+    /// Rotate around _z_, then _y_, then _x_.
+    Zyx,
+}
+
 impl<S: BaseFloat> From<Quaternion<S>> for Euler<Rad<S>> {
     fn from(src: Quaternion<S>) -> Euler<Rad<S>> {
         let sig: S = cast(0.499).unwrap();
@@ -190,16 +283,16 @@ impl<A: Angle> approx::UlpsEq for Euler<A> {
 }
 
 #[cfg(feature = "rand")]
-impl<A> Distribution<Euler<A>> for Standard
+impl<A> Distribution<Euler<A>> for StandardUniform
 where
-    Standard: Distribution<A>,
+    StandardUniform: Distribution<A>,
     A: Angle,
 {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Euler<A> {
         Euler {
-            x: rng.gen(),
-            y: rng.gen(),
-            z: rng.gen(),
+            x: rng.random(),
+            y: rng.random(),
+            z: rng.random(),
         }
     }
 }
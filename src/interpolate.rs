@@ -0,0 +1,118 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num::BaseFloat;
+use vector::{Vector1, Vector2, Vector3, Vector4};
+
+/// Clamp `x` into the range `[min, max]`.
+///
+/// Unlike `Ord::clamp`, this works with floating point numbers and angles.
+/// A NaN `x` is returned unchanged.
+#[inline]
+pub fn clamp<S: PartialOrd>(x: S, min: S, max: S) -> S {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+/// Clamp `x` into the range `[0, 1]`, like the `saturate` intrinsic in HLSL.
+#[inline]
+pub fn saturate<S: BaseFloat>(x: S) -> S {
+    clamp(x, S::zero(), S::one())
+}
+
+/// Values that can be smoothly interpolated between two edges.
+///
+/// This is implemented for floating point scalars, and component-wise for
+/// vectors.
+pub trait SmoothStep: Copy {
+    /// Hermite interpolation between `edge0` and `edge1`, using the
+    /// polynomial `3t² - 2t³`. `x` is clamped into `[edge0, edge1]` first.
+    fn smoothstep(edge0: Self, edge1: Self, x: Self) -> Self;
+
+    /// Ken Perlin's variant of `smoothstep`, using the polynomial
+    /// `6t⁵ - 15t⁴ + 10t³`, which also has zero second derivatives at the
+    /// edges. `x` is clamped into `[edge0, edge1]` first.
+    fn smootherstep(edge0: Self, edge1: Self, x: Self) -> Self;
+}
+
+#[inline]
+fn clamped_ratio<S: BaseFloat>(edge0: S, edge1: S, x: S) -> S {
+    // `max` and `min` discard NaN, so equal edges give `0` rather than the
+    // NaN `saturate` would pass through
+    let t = (x - edge0) / (edge1 - edge0);
+    t.max(S::zero()).min(S::one())
+}
+
+impl<S: BaseFloat> SmoothStep for S {
+    #[inline]
+    fn smoothstep(edge0: S, edge1: S, x: S) -> S {
+        let t = clamped_ratio(edge0, edge1, x);
+        let two = S::one() + S::one();
+        let three = two + S::one();
+        t * t * (three - two * t)
+    }
+
+    #[inline]
+    fn smootherstep(edge0: S, edge1: S, x: S) -> S {
+        let t = clamped_ratio(edge0, edge1, x);
+        let six = S::from(6).unwrap();
+        let ten = S::from(10).unwrap();
+        let fifteen = S::from(15).unwrap();
+        t * t * t * (t * (t * six - fifteen) + ten)
+    }
+}
+
+macro_rules! impl_smoothstep_vector {
+    ($VectorN:ident { $($field:ident),+ }) => {
+        impl<S: BaseFloat> SmoothStep for $VectorN<S> {
+            #[inline]
+            fn smoothstep(edge0: $VectorN<S>, edge1: $VectorN<S>, x: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(S::smoothstep(edge0.$field, edge1.$field, x.$field)),+)
+            }
+
+            #[inline]
+            fn smootherstep(edge0: $VectorN<S>, edge1: $VectorN<S>, x: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(S::smootherstep(edge0.$field, edge1.$field, x.$field)),+)
+            }
+        }
+    };
+}
+
+impl_smoothstep_vector!(Vector1 { x });
+impl_smoothstep_vector!(Vector2 { x, y });
+impl_smoothstep_vector!(Vector3 { x, y, z });
+impl_smoothstep_vector!(Vector4 { x, y, z, w });
+
+/// Smooth Hermite interpolation of `x` between `edge0` and `edge1`.
+///
+/// This is the equivalent to the GLSL [`smoothstep`] function.
+///
+/// [`smoothstep`]: https://registry.khronos.org/OpenGL-Refpages/gl4/html/smoothstep.xhtml
+#[inline]
+pub fn smoothstep<T: SmoothStep>(edge0: T, edge1: T, x: T) -> T {
+    T::smoothstep(edge0, edge1, x)
+}
+
+/// Smoother interpolation of `x` between `edge0` and `edge1`, with zero first
+/// and second derivatives at the edges.
+#[inline]
+pub fn smootherstep<T: SmoothStep>(edge0: T, edge1: T, x: T) -> T {
+    T::smootherstep(edge0, edge1, x)
+}
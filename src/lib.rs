@@ -82,12 +82,17 @@ pub use matrix::{Matrix2, Matrix3, Matrix4};
 pub use quaternion::Quaternion;
 pub use vector::{dot, vec1, vec2, vec3, vec4, Vector1, Vector2, Vector3, Vector4};
 
-pub use angle::{Deg, Rad};
-pub use euler::Euler;
+pub use angle::{AngleExt, Deg, Rad};
+#[cfg(feature = "rand")]
+pub use angle::UniformAngle;
+pub use bounds::{Ray3, Sphere3};
+pub use euler::{Euler, EulerOrder};
 pub use point::{point1, point2, point3, Point1, Point2, Point3};
 pub use rotation::*;
+pub use rotor::Rotor3;
 pub use transform::*;
 
+pub use interpolate::{clamp, saturate, smootherstep, smoothstep, SmoothStep};
 pub use projection::*;
 
 // Modules
@@ -112,9 +117,12 @@ mod vector;
 mod vector_simd;
 
 mod angle;
+mod bounds;
 mod euler;
 mod point;
 mod rotation;
+mod rotor;
 mod transform;
 
+mod interpolate;
 mod projection;
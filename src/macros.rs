@@ -180,6 +180,18 @@ macro_rules! impl_fixed_array_conversions {
                 unsafe { mem::transmute(v) }
             }
         }
+
+        impl<'a, $S: Clone> TryFrom<&'a [$S]> for $ArrayN<$S> {
+            type Error = SliceLengthError;
+
+            #[inline]
+            fn try_from(v: &'a [$S]) -> Result<$ArrayN<$S>, SliceLengthError> {
+                if v.len() != $n {
+                    return Err(SliceLengthError { expected: $n, found: v.len() });
+                }
+                Ok($ArrayN { $($field: v[$index].clone()),+ })
+            }
+        }
     }
 }
 
@@ -253,6 +265,51 @@ macro_rules! impl_index_operators {
     };
 }
 
+/// Generates lexicographic `PartialOrd` and `Ord` implementations, comparing
+/// the elements in order. These are only provided for totally ordered element
+/// types, so that floating point values stay unordered.
+macro_rules! impl_lexicographic_ord {
+    ($VectorN:ident<$S:ident>, $n:expr) => {
+        impl<$S: Ord> PartialOrd for $VectorN<$S> {
+            #[inline]
+            fn partial_cmp(&self, other: &$VectorN<$S>) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$S: Ord> Ord for $VectorN<$S> {
+            #[inline]
+            fn cmp(&self, other: &$VectorN<$S>) -> ::std::cmp::Ordering {
+                let lhs: &[$S; $n] = self.as_ref();
+                let rhs: &[$S; $n] = other.as_ref();
+                lhs.cmp(rhs)
+            }
+        }
+    };
+}
+
+/// Generates `Index<$Axis>` and `IndexMut<$Axis>` implementations that
+/// return the field named by the axis.
+macro_rules! impl_axis_index_operators {
+    ($VectorN:ident<$S:ident> { $($Variant:ident: $field:ident),+ }, $Axis:ident) => {
+        impl<$S> Index<$Axis> for $VectorN<$S> {
+            type Output = $S;
+
+            #[inline]
+            fn index(&self, axis: $Axis) -> &$S {
+                match axis { $($Axis::$Variant => &self.$field),+ }
+            }
+        }
+
+        impl<$S> IndexMut<$Axis> for $VectorN<$S> {
+            #[inline]
+            fn index_mut(&mut self, axis: $Axis) -> &mut $S {
+                match axis { $($Axis::$Variant => &mut self.$field),+ }
+            }
+        }
+    };
+}
+
 /// Generates a binary operator implementation for the permutations of by-ref and by-val, for simd
 #[cfg(feature = "simd")]
 macro_rules! impl_operator_simd {
@@ -381,4 +438,30 @@ macro_rules! impl_bytemuck_cast {
     };
 }
 
+/// Generate `Hash` and `Eq` implementations for `FloatHash`-wrapped `f32` and
+/// `f64` types, comparing the canonical bit patterns of the listed fields
+#[cfg(feature = "float-hash")]
+macro_rules! impl_float_hash {
+    ($T:ident { $($($field:ident).+),+ }) => {
+        impl_float_hash!(@impl $T<f32> { $($($field).+),+ });
+        impl_float_hash!(@impl $T<f64> { $($($field).+),+ });
+    };
+    (@impl $T:ident<$S:ident> { $($($field:ident).+),+ }) => {
+        impl ::std::hash::Hash for FloatHash<$T<$S>> {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                $(::std::hash::Hash::hash(&self.0.$($field).+.canonical_bits(), state);)+
+            }
+        }
+
+        impl PartialEq for FloatHash<$T<$S>> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                $(self.0.$($field).+.canonical_bits() == other.0.$($field).+.canonical_bits())&&+
+            }
+        }
+
+        impl Eq for FloatHash<$T<$S>> {}
+    };
+}
+
 include!(concat!(env!("OUT_DIR"), "/swizzle_operator_macro.rs"));
@@ -16,7 +16,7 @@
 use num_traits::{cast, NumCast};
 #[cfg(feature = "rand")]
 use rand::{
-    distributions::{Distribution, Standard},
+    distr::{Distribution, StandardUniform},
     Rng,
 };
 use std::fmt;
@@ -29,7 +29,7 @@ use structure::*;
 
 use angle::Rad;
 use approx;
-use euler::Euler;
+use euler::{Euler, EulerOrder};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
 use quaternion::Quaternion;
@@ -118,6 +118,9 @@ impl<S: BaseFloat> Matrix2<S> {
         Matrix2::from_cols(basis1, basis2)
     }
 
+    /// Create a rotation matrix that rotates counter-clockwise by `theta`.
+    ///
+    /// Use [`Basis2`](struct.Basis2.html) where a `Rotation2` is expected.
     #[inline]
     pub fn from_angle<A: Into<Rad<S>>>(theta: A) -> Matrix2<S> {
         let (s, c) = Rad::sin_cos(theta.into());
@@ -156,10 +159,30 @@ impl<S> Matrix3<S> {
             z: c2,
         }
     }
+
+    /// Create a new matrix from an array of rows.
+    ///
+    /// This is the transpose of the `From<[[S; 3]; 3]>` conversion, which
+    /// interprets the inner arrays as columns.
+    #[inline]
+    pub fn from_row_major(rows: [[S; 3]; 3]) -> Matrix3<S> {
+        let [[c0r0, c1r0, c2r0], [c0r1, c1r1, c2r1], [c0r2, c1r2, c2r2]] = rows;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix3::new(
+            c0r0, c0r1, c0r2,
+            c1r0, c1r1, c1r2,
+            c2r0, c2r1, c2r2,
+        )
+    }
 }
 
 impl<S: BaseFloat> Matrix3<S> {
     /// Create a homogeneous transformation matrix from a translation vector.
+    ///
+    /// Together with `from_scale`, `from_nonuniform_scale` and `from_shear`,
+    /// this builds 2D affine transforms, which are applied to `Point2` and
+    /// `Vector2` through `Transform<Point2<S>>`.
     #[inline]
     pub fn from_translation(v: Vector2<S>) -> Matrix3<S> {
         #[cfg_attr(rustfmt, rustfmt_skip)]
@@ -187,6 +210,21 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// Create a homogeneous shear matrix from two shear factors.
+    ///
+    /// The factor `xy` shears x by y, and `yx` shears y by x, so a point
+    /// `(x, y)` is mapped to `(x + xy * y, y + yx * x)`. In terms of
+    /// entries, `xy` is stored in `self[1][0]` and `yx` in `self[0][1]`.
+    #[inline]
+    pub fn from_shear(xy: S, yx: S) -> Matrix3<S> {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix3::new(
+            S::one(), yx, S::zero(),
+            xy, S::one(), S::zero(),
+            S::zero(), S::zero(), S::one(),
+        )
+    }
+
     /// Create a rotation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     #[deprecated = "Use Matrix3::look_to_lh"]
@@ -272,6 +310,90 @@ impl<S: BaseFloat> Matrix3<S> {
         )
     }
 
+    /// Create a rotation matrix from a set of Euler angles, using the default
+    /// XYZ rotation order.
+    ///
+    /// This is equivalent to `Matrix3::from(euler)`.
+    #[inline]
+    pub fn from_euler(euler: Euler<Rad<S>>) -> Matrix3<S> {
+        Matrix3::from(euler)
+    }
+
+    /// Create a rotation matrix from rotations around the `x`, `y` and `z`
+    /// axes, applied in the given order.
+    pub fn from_euler_order<A: Into<Rad<S>>>(x: A, y: A, z: A, order: EulerOrder) -> Matrix3<S> {
+        let rx = Matrix3::from_angle_x(x);
+        let ry = Matrix3::from_angle_y(y);
+        let rz = Matrix3::from_angle_z(z);
+
+        match order {
+            EulerOrder::Xyz => rx * ry * rz,
+            EulerOrder::Xzy => rx * rz * ry,
+            EulerOrder::Yxz => ry * rx * rz,
+            EulerOrder::Yzx => ry * rz * rx,
+            EulerOrder::Zxy => rz * rx * ry,
+            EulerOrder::Zyx => rz * ry * rx,
+        }
+    }
+
+    /// The transpose of the inverse of the matrix, or `None` if the matrix
+    /// is not invertible.
+    ///
+    /// This is the matrix to use for transforming surface normals, which
+    /// would otherwise stop being perpendicular to the surface under a
+    /// non-uniform scale.
+    #[inline]
+    pub fn invert_transpose(&self) -> Option<Matrix3<S>> {
+        self.invert().map(|m| m.transpose())
+    }
+
+    /// Split the matrix into an orthogonal matrix `R` and a symmetric matrix
+    /// `S` such that `self == R * S`, returned as `(R, S)`.
+    ///
+    /// `R` is the orthogonal matrix closest to `self`, which makes this useful
+    /// for removing shear and scale from a rotation that has drifted. It is
+    /// computed by Higham's method, repeatedly averaging the matrix with its
+    /// inverse transpose until it converges.
+    ///
+    /// The matrix must be invertible. If its determinant is negative, `R`
+    /// contains a reflection.
+    pub fn polar_decomposition(&self) -> (Matrix3<S>, Matrix3<S>) {
+        const MAX_ITERATIONS: usize = 32;
+
+        let half: S = cast(0.5f64).unwrap();
+        let mut r = *self;
+        for _ in 0..MAX_ITERATIONS {
+            let next = match r.invert_transpose() {
+                Some(inv_t) => (r + inv_t) * half,
+                None => break,
+            };
+            let converged = ulps_eq!(next, &r);
+            r = next;
+            if converged {
+                break;
+            }
+        }
+
+        // `R^T * M` is symmetric in exact arithmetic; average away the rounding
+        let s = r.transpose() * *self;
+        (r, (s + s.transpose()) * half)
+    }
+
+    /// The adjugate of this matrix, i.e. the transpose of its cofactor
+    /// matrix.
+    ///
+    /// This satisfies `m.adjugate() * m == m.determinant() * I`, so for an
+    /// invertible matrix it is the inverse scaled by the determinant.
+    #[inline]
+    pub fn adjugate(&self) -> Matrix3<S> {
+        Matrix3::from_cols(
+            self[1].cross(self[2]),
+            self[2].cross(self[0]),
+            self[0].cross(self[1]),
+        )
+        .transpose()
+    }
+
     /// Are all entries in the matrix finite.
     pub fn is_finite(&self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
@@ -311,6 +433,63 @@ impl<S> Matrix4<S> {
             w: c3,
         }
     }
+
+    /// Create a new matrix from a flat array in column-major order.
+    ///
+    /// Element `4 * c + r` of the array becomes column `c`, row `r` of the
+    /// matrix, which is the layout expected by OpenGL and most GPU APIs.
+    #[inline]
+    pub fn from_array(array: [S; 16]) -> Matrix4<S> {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let [
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        ] = array;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        )
+    }
+
+    /// View the matrix as a flat array in column-major order, without
+    /// copying.
+    ///
+    /// `Matrix4` is `#[repr(C)]`, so its storage is exactly this array:
+    /// column `c`, row `r` is at element `4 * c + r`, matching `from_array`
+    /// and the `AsRef<[S; 16]>` implementation. This makes it suitable for
+    /// uploading to uniform buffers, e.g. with `bytemuck`.
+    #[inline]
+    pub fn as_array(&self) -> &[S; 16] {
+        self.as_ref()
+    }
+
+    /// Copy the matrix into a flat array in column-major order.
+    ///
+    /// Column `c`, row `r` of the matrix is stored at element `4 * c + r`,
+    /// so this is the inverse of `from_array`.
+    #[inline]
+    pub fn to_array(&self) -> [S; 16]
+    where
+        S: Copy,
+    {
+        *self.as_array()
+    }
+
+    /// Copy the matrix into an array of columns, so that `array[c][r]` is
+    /// column `c`, row `r` of the matrix.
+    #[inline]
+    pub fn to_array_2d(&self) -> [[S; 4]; 4]
+    where
+        S: Copy,
+    {
+        (*self).into()
+    }
 }
 
 impl<S: BaseFloat> Matrix4<S> {
@@ -344,6 +523,51 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Create a homogeneous shear matrix from six shear factors.
+    ///
+    /// Each factor is named after the axis it shears followed by the axis it
+    /// shears by, so a point `(x, y, z)` is mapped to:
+    ///
+    /// ```text
+    /// (x + xy * y + xz * z,
+    ///  y + yx * x + yz * z,
+    ///  z + zx * x + zy * y)
+    /// ```
+    ///
+    /// In terms of entries, the factor `ab` is stored in `self[b][a]`, i.e.
+    /// column `b`, row `a`.
+    #[inline]
+    pub fn from_shear(xy: S, xz: S, yx: S, yz: S, zx: S, zy: S) -> Matrix4<S> {
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            S::one(), yx, zx, S::zero(),
+            xy, S::one(), zy, S::zero(),
+            xz, yz, S::one(), S::zero(),
+            S::zero(), S::zero(), S::zero(), S::one(),
+        )
+    }
+
+    /// Create a homogeneous transformation matrix from a translation, a
+    /// rotation and a per-axis scale.
+    ///
+    /// This is the product `T * R * S`, so points are scaled first, then
+    /// rotated, then translated. The rotation should be a unit quaternion.
+    /// `translation` and `scale` recover the translation and scale of the
+    /// result, as long as the scale factors are positive.
+    pub fn from_trs(
+        translation: Vector3<S>,
+        rotation: Quaternion<S>,
+        scale: Vector3<S>,
+    ) -> Matrix4<S> {
+        let r = Matrix3::from(rotation);
+        Matrix4::from_cols(
+            (r.x * scale.x).extend(S::zero()),
+            (r.y * scale.y).extend(S::zero()),
+            (r.z * scale.z).extend(S::zero()),
+            translation.extend(S::one()),
+        )
+    }
+
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     #[deprecated = "Use Matrix4::look_to_rh"]
@@ -377,6 +601,36 @@ impl<S: BaseFloat> Matrix4<S> {
         )
     }
 
+    /// Create a homogeneous transformation matrix that will cause a vector to point at
+    /// `dir`, using `up` for orientation, and then roll the camera by `roll` about the
+    /// view direction.
+    ///
+    /// The roll follows the right-hand rule around `dir`, so a positive angle tilts the
+    /// camera's up axis towards its right axis. A roll of zero gives the same matrix as
+    /// `look_to_rh`.
+    pub fn look_to_rh_roll<A: Into<Rad<S>>>(
+        eye: Point3<S>,
+        dir: Vector3<S>,
+        up: Vector3<S>,
+        roll: A,
+    ) -> Matrix4<S> {
+        let f = dir.normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+
+        // rotate the right and up axes about the forward axis
+        let (sin, cos) = Rad::sin_cos(roll.into());
+        let (s, u) = (s * cos - u * sin, u * cos + s * sin);
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            s.x, u.x, -f.x, S::zero(),
+            s.y, u.y, -f.y, S::zero(),
+            s.z, u.z, -f.z, S::zero(),
+            -eye.dot(s), -eye.dot(u), eye.dot(f), S::one(),
+        )
+    }
+
     /// Create a homogeneous transformation matrix that will cause a vector to point at
     /// `dir`, using `up` for orientation.
     pub fn look_to_lh(eye: Point3<S>, dir: Vector3<S>, up: Vector3<S>) -> Matrix4<S> {
@@ -476,6 +730,279 @@ impl<S: BaseFloat> Matrix4<S> {
     pub fn is_finite(&self) -> bool {
         self.w.is_finite() && self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
     }
+
+    /// Create a new matrix from a flat array in column-major order, as
+    /// `from_array` does, or return `None` if any element is NaN or infinite.
+    ///
+    /// This is useful for validating matrices that come from untrusted
+    /// sources, such as files or the network.
+    #[inline]
+    pub fn from_finite_array(array: [S; 16]) -> Option<Matrix4<S>> {
+        let m = Matrix4::from_array(array);
+        if m.is_finite() {
+            Some(m)
+        } else {
+            None
+        }
+    }
+
+    /// Create a homogeneous transformation matrix that places an object at
+    /// `position`, with its local `+z` axis pointing at `camera_pos` and its
+    /// local `+y` axis towards `up`.
+    ///
+    /// If `position` and `camera_pos` coincide there is no direction to
+    /// face, and the result is a plain translation to `position`. If `up`
+    /// is parallel to the direction of the camera, the world axis least
+    /// aligned with that direction is used in place of `up`.
+    pub fn billboard(position: Point3<S>, camera_pos: Point3<S>, up: Vector3<S>) -> Matrix4<S> {
+        let to_camera = camera_pos - position;
+        if ulps_eq!(to_camera, &Vector3::zero()) {
+            return Matrix4::from_translation(position.to_vec());
+        }
+
+        let z = to_camera.normalize();
+        let mut x = up.cross(z);
+        if ulps_eq!(x, &Vector3::zero()) {
+            let (ax, ay, az) = (z.x.abs(), z.y.abs(), z.z.abs());
+            let up = if ax <= ay && ax <= az {
+                Vector3::unit_x()
+            } else if ay <= az {
+                Vector3::unit_y()
+            } else {
+                Vector3::unit_z()
+            };
+            x = up.cross(z);
+        }
+        let x = x.normalize();
+        let y = z.cross(x);
+        Matrix4::from_cols(
+            x.extend(S::zero()),
+            y.extend(S::zero()),
+            z.extend(S::zero()),
+            position.to_homogeneous(),
+        )
+    }
+
+    /// Like `billboard`, but only rotates around `up`, which stays the local
+    /// `+y` axis. The local `+z` axis points at `camera_pos` as closely as
+    /// this allows.
+    ///
+    /// If the camera lies on the `up` axis through `position` there is no
+    /// direction to face, and the result is a plain translation to
+    /// `position`.
+    pub fn billboard_cylindrical(
+        position: Point3<S>,
+        camera_pos: Point3<S>,
+        up: Vector3<S>,
+    ) -> Matrix4<S> {
+        let y = up.normalize();
+        let to_camera = camera_pos - position;
+        let to_camera = to_camera - y * to_camera.dot(y);
+        if ulps_eq!(to_camera, &Vector3::zero()) {
+            return Matrix4::from_translation(position.to_vec());
+        }
+
+        let z = to_camera.normalize();
+        let x = y.cross(z);
+        Matrix4::from_cols(
+            x.extend(S::zero()),
+            y.extend(S::zero()),
+            z.extend(S::zero()),
+            position.to_homogeneous(),
+        )
+    }
+
+    /// Transform a point by this matrix, including the perspective divide.
+    ///
+    /// This is the same as `Transform::transform_point`, without having to
+    /// import the trait.
+    #[inline]
+    pub fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Transform::transform_point(self, point)
+    }
+
+    /// Transform a vector by this matrix. The vector is treated as a
+    /// direction with a `w` of zero, so it is not affected by translation.
+    ///
+    /// This is the same as `Transform::transform_vector`, without having to
+    /// import the trait.
+    #[inline]
+    pub fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        Transform::transform_vector(self, vec)
+    }
+
+    /// Transform each point in `src` by this matrix, including the
+    /// perspective divide, and write the results to `dst`.
+    ///
+    /// This gives the same results as calling `transform_point` on each point
+    /// in turn, but gathers the rows of the matrix only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    pub fn transform_points(&self, src: &[Point3<S>], dst: &mut [Point3<S>]) {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "`src` and `dst` must have the same length"
+        );
+
+        let (r0, r1, r2, r3) = (self.row(0), self.row(1), self.row(2), self.row(3));
+        for (out, p) in dst.iter_mut().zip(src) {
+            let h = p.to_homogeneous();
+            let v = Vector4::new(r0.dot(h), r1.dot(h), r2.dot(h), r3.dot(h));
+            *out = Point3::from_homogeneous(v);
+        }
+    }
+
+    /// Extract the six clipping planes of the frustum described by this
+    /// view-projection matrix, using the method of Gribb and Hartmann.
+    ///
+    /// The planes are returned in the order left, right, bottom, top, near,
+    /// far. Each plane `(a, b, c, d)` is normalized so that `(a, b, c)` is a
+    /// unit normal pointing into the frustum, and `a * x + b * y + c * z + d`
+    /// is the signed distance of a point from the plane. A point is inside the
+    /// frustum if its distance to all six planes is non-negative.
+    ///
+    /// This assumes OpenGL clip space conventions, with a depth range of
+    /// `[-1, 1]`, as produced by `perspective`, `frustum` and `ortho`.
+    pub fn frustum_planes(&self) -> [Vector4<S>; 6] {
+        let (r0, r1, r2, r3) = (self.row(0), self.row(1), self.row(2), self.row(3));
+        let normalize = |p: Vector4<S>| p / p.truncate().magnitude();
+        [
+            normalize(r3 + r0),
+            normalize(r3 - r0),
+            normalize(r3 + r1),
+            normalize(r3 - r1),
+            normalize(r3 + r2),
+            normalize(r3 - r2),
+        ]
+    }
+
+    /// Invert this matrix, returning `fallback` if it is not invertible.
+    ///
+    /// This is a non-panicking alternative to `m.invert().unwrap()` for
+    /// matrices that may become degenerate at runtime, for example a scale of
+    /// zero while editing. Pass `Matrix4::identity()` to substitute the
+    /// identity.
+    ///
+    /// Like `invert`, this only treats a determinant of exactly zero as
+    /// singular. Nearly singular matrices are still inverted and can produce
+    /// very large entries; check `is_invertible_with_epsilon` first if that
+    /// matters.
+    #[inline]
+    pub fn invert_or(&self, fallback: Matrix4<S>) -> Matrix4<S> {
+        self.invert().unwrap_or(fallback)
+    }
+
+    /// The adjugate of this matrix, i.e. the transpose of its cofactor
+    /// matrix.
+    ///
+    /// This satisfies `m.adjugate() * m == m.determinant() * I`, so for an
+    /// invertible matrix it is the inverse scaled by the determinant.
+    pub fn adjugate(&self) -> Matrix4<S> {
+        let t = self.transpose();
+        let cf = |i, j| {
+            let mat = match i {
+                0 => Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                1 => Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j)),
+                2 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j)),
+                3 => Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j)),
+                _ => panic!("out of range"),
+            };
+            let sign = if (i + j) & 1 == 1 {
+                -S::one()
+            } else {
+                S::one()
+            };
+            mat.determinant() * sign
+        };
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix4::new(
+            cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
+            cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
+            cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
+            cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
+        )
+    }
+
+    /// The matrix for transforming surface normals by this homogeneous
+    /// transformation matrix, or `None` if its linear part is not invertible.
+    ///
+    /// This is the inverse transpose of the upper-left 3×3 part of the matrix.
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Matrix3<S>> {
+        Matrix3::from_cols(self.x.truncate(), self.y.truncate(), self.z.truncate())
+            .invert_transpose()
+    }
+
+    /// The translation part of a homogeneous transformation matrix.
+    #[inline]
+    pub fn translation(&self) -> Vector3<S> {
+        self.w.truncate()
+    }
+
+    /// The per-axis scale of a homogeneous transformation matrix, taken as
+    /// the magnitudes of the columns of its linear part.
+    ///
+    /// This assumes the matrix is affine. Negative scales cannot be
+    /// recovered, since the column magnitudes are always non-negative.
+    #[inline]
+    pub fn scale(&self) -> Vector3<S> {
+        Vector3::new(
+            self.x.truncate().magnitude(),
+            self.y.truncate().magnitude(),
+            self.z.truncate().magnitude(),
+        )
+    }
+
+    /// Interpolate between two homogeneous transformation matrices.
+    ///
+    /// Rather than interpolating the entries directly, both matrices are
+    /// decomposed into a translation, rotation and scale as built by
+    /// `from_trs`. The translations and scales are interpolated linearly, the
+    /// rotations spherically, and the result is recomposed. This keeps the
+    /// rotation part of the result orthogonal, which lerping the entries does
+    /// not.
+    ///
+    /// Returns `None` if either matrix cannot be decomposed, i.e. it is not
+    /// affine, has a zero scale factor or contains a reflection. Shear is not
+    /// accounted for, so matrices should be built from translations,
+    /// rotations and scales only.
+    pub fn interpolate(&self, other: &Matrix4<S>, t: S) -> Option<Matrix4<S>> {
+        let (t0, r0, s0) = self.decompose_trs()?;
+        let (t1, r1, s1) = other.decompose_trs()?;
+        Some(Matrix4::from_trs(
+            t0.lerp(t1, t),
+            r0.slerp(r1, t),
+            s0.lerp(s1, t),
+        ))
+    }
+
+    /// Split an affine matrix into the translation, rotation and scale that
+    /// `from_trs` would build it from.
+    fn decompose_trs(&self) -> Option<(Vector3<S>, Quaternion<S>, Vector3<S>)> {
+        if !ulps_eq!(self.row(3), Vector4::unit_w()) {
+            return None;
+        }
+        let scale = self.scale();
+        if ulps_eq!(scale.x, &S::zero())
+            || ulps_eq!(scale.y, &S::zero())
+            || ulps_eq!(scale.z, &S::zero())
+        {
+            return None;
+        }
+        let rotation = Matrix3::from_cols(
+            self.x.truncate() / scale.x,
+            self.y.truncate() / scale.y,
+            self.z.truncate() / scale.z,
+        );
+        if rotation.determinant() < S::zero() {
+            return None;
+        }
+        Some((self.translation(), Quaternion::from(rotation), scale))
+    }
 }
 
 impl<S: BaseFloat> Zero for Matrix2<S> {
@@ -745,14 +1272,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix3<S> {
         if det == S::zero() {
             None
         } else {
-            Some(
-                Matrix3::from_cols(
-                    self[1].cross(self[2]) / det,
-                    self[2].cross(self[0]) / det,
-                    self[0].cross(self[1]) / det,
-                )
-                .transpose(),
-            )
+            Some(self.adjugate() / det)
         }
     }
 
@@ -871,39 +1391,7 @@ impl<S: BaseFloat> SquareMatrix for Matrix4<S> {
         if det == S::zero() {
             None
         } else {
-            let inv_det = S::one() / det;
-            let t = self.transpose();
-            let cf = |i, j| {
-                let mat = match i {
-                    0 => {
-                        Matrix3::from_cols(t.y.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    1 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.z.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    2 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.w.truncate_n(j))
-                    }
-                    3 => {
-                        Matrix3::from_cols(t.x.truncate_n(j), t.y.truncate_n(j), t.z.truncate_n(j))
-                    }
-                    _ => panic!("out of range"),
-                };
-                let sign = if (i + j) & 1 == 1 {
-                    -S::one()
-                } else {
-                    S::one()
-                };
-                mat.determinant() * sign * inv_det
-            };
-
-            #[cfg_attr(rustfmt, rustfmt_skip)]
-            Some(Matrix4::new(
-                cf(0, 0), cf(0, 1), cf(0, 2), cf(0, 3),
-                cf(1, 0), cf(1, 1), cf(1, 2), cf(1, 3),
-                cf(2, 0), cf(2, 1), cf(2, 2), cf(2, 3),
-                cf(3, 0), cf(3, 1), cf(3, 2), cf(3, 3),
-            ))
+            Some(self.adjugate() * (S::one() / det))
         }
     }
     #[cfg(feature = "simd")]
@@ -1274,6 +1762,33 @@ macro_rules! impl_matrix {
         impl_scalar_ops!($MatrixN<f64> { $($field),+ });
 
 
+        impl<S: BaseFloat> $MatrixN<S> {
+            /// The largest absolute value of the entries. NaN entries are
+            /// ignored.
+            ///
+            /// This is the max norm of the matrix, which is not the same as the
+            /// infinity norm induced by the vector infinity norm (the largest
+            /// absolute row sum).
+            #[inline]
+            pub fn max_abs(&self) -> S {
+                S::zero()$(.max(self.$field.max_abs()))+
+            }
+        }
+
+        impl<S> $MatrixN<S> {
+            /// Construct a new matrix by calling `f` with the `(row, column)`
+            /// index of each element.
+            ///
+            /// The elements are visited in column-major order, matching the
+            /// memory layout.
+            #[inline]
+            pub fn from_fn<F>(mut f: F) -> $MatrixN<S>
+                where F: FnMut(usize, usize) -> S
+            {
+                $MatrixN { $($field: $VectorN::from_fn(|row| f(row, $row_index))),+ }
+            }
+        }
+
         impl<S: NumCast + Copy> $MatrixN<S> {
             /// Component-wise casting to another type
             #[inline]
@@ -1514,6 +2029,15 @@ macro_rules! fixed_array_conversions {
             }
         }
 
+        impl<$S: Clone> $MatrixN<$S> {
+            /// Collect the elements into a `Vec` in column-major order, the
+            /// same order as the memory layout.
+            #[inline]
+            pub fn to_component_vec(&self) -> Vec<$S> {
+                AsRef::<[$S; ($n * $n)]>::as_ref(self).to_vec()
+            }
+        }
+
         // impl<$S> From<[$S; ($n * $n)]> for $MatrixN<$S> {
         //     #[inline]
         //     fn from(m: [$S; ($n * $n)]) -> $MatrixN<$S> {
@@ -1622,6 +2146,11 @@ impl<S: BaseNum> From<Matrix3<S>> for Matrix4<S> {
 
 impl<S: BaseFloat> From<Matrix3<S>> for Quaternion<S> {
     /// Convert the matrix to a quaternion
+    ///
+    /// The matrix is assumed to be a proper rotation, i.e. orthonormal with a
+    /// determinant of `1`. To stay numerically stable when the trace is
+    /// negative (rotations near a half turn), the component with the largest
+    /// diagonal term is computed first and the others are derived from it.
     fn from(mat: Matrix3<S>) -> Quaternion<S> {
         // http://www.cs.ucr.edu/~vbz/resources/quatut.pdf
         let trace = mat.trace();
@@ -1684,10 +2213,57 @@ impl<S: fmt::Debug> fmt::Debug for Matrix4<S> {
     }
 }
 
+/// Write an `n`×`n` matrix stored in column-major order as rows, with every
+/// element formatted using the precision of `f` and right-aligned to the
+/// widest one.
+fn fmt_matrix<S: fmt::Display>(f: &mut fmt::Formatter, n: usize, elements: &[S]) -> fmt::Result {
+    let cells: Vec<String> = (0..n)
+        .flat_map(|r| (0..n).map(move |c| &elements[c * n + r]))
+        .map(|e| match f.precision() {
+            Some(precision) => format!("{:.*}", precision, e),
+            None => format!("{}", e),
+        })
+        .collect();
+    let width = cells.iter().map(|c| c.chars().count()).max().unwrap_or(0);
+
+    for (r, row) in cells.chunks(n).enumerate() {
+        if r > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[")?;
+        for (c, cell) in row.iter().enumerate() {
+            if c > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:>width$}", cell, width = width)?;
+        }
+        write!(f, "]")?;
+    }
+    Ok(())
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_matrix(f, 2, AsRef::<[S; 4]>::as_ref(self))
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_matrix(f, 3, AsRef::<[S; 9]>::as_ref(self))
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Matrix4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_matrix(f, 4, AsRef::<[S; 16]>::as_ref(self))
+    }
+}
+
 #[cfg(feature = "rand")]
-impl<S> Distribution<Matrix2<S>> for Standard
+impl<S> Distribution<Matrix2<S>> for StandardUniform
 where
-    Standard: Distribution<Vector2<S>>,
+    StandardUniform: Distribution<Vector2<S>>,
     S: BaseNum,
 {
     #[inline]
@@ -1700,34 +2276,34 @@ where
 }
 
 #[cfg(feature = "rand")]
-impl<S> Distribution<Matrix3<S>> for Standard
+impl<S> Distribution<Matrix3<S>> for StandardUniform
 where
-    Standard: Distribution<Vector3<S>>,
+    StandardUniform: Distribution<Vector3<S>>,
     S: BaseNum,
 {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix3<S> {
         Matrix3 {
-            x: rng.gen(),
-            y: rng.gen(),
-            z: rng.gen(),
+            x: rng.random(),
+            y: rng.random(),
+            z: rng.random(),
         }
     }
 }
 
 #[cfg(feature = "rand")]
-impl<S> Distribution<Matrix4<S>> for Standard
+impl<S> Distribution<Matrix4<S>> for StandardUniform
 where
-    Standard: Distribution<Vector4<S>>,
+    StandardUniform: Distribution<Vector4<S>>,
     S: BaseNum,
 {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Matrix4<S> {
         Matrix4 {
-            x: rng.gen(),
-            y: rng.gen(),
-            z: rng.gen(),
-            w: rng.gen(),
+            x: rng.random(),
+            y: rng.random(),
+            z: rng.random(),
+            w: rng.random(),
         }
     }
 }
@@ -67,3 +67,64 @@ impl<T> BaseFloat for T where
         + approx::UlpsEq<Epsilon = Self>
 {
 }
+
+/// A wrapper that hashes and compares floating point vectors and quaternions
+/// by the bit patterns of their components.
+///
+/// This makes it possible to use float-valued types as keys in a `HashMap`
+/// or `HashSet`, e.g. for caching transforms computed from float inputs.
+/// It is available with the `float-hash` feature.
+///
+/// # Footguns
+///
+/// Equality here is *not* numeric equality. Two values are only the same key
+/// if their components are bit-for-bit identical, so results that differ in
+/// the last ulp, such as `0.1 + 0.2` and `0.3`, are distinct keys. No
+/// tolerance is applied, which is usually what you want for a cache but
+/// never what you want for geometry.
+///
+/// To keep `Eq` lawful and consistent with `Hash`, two cases are normalized
+/// before comparing or hashing:
+///
+/// - `-0.0` is treated as `0.0`, matching `==` on the underlying floats.
+/// - All NaNs are treated as the same value, regardless of sign or payload.
+///   Unlike `==` on floats, a NaN component therefore *equals* another NaN
+///   component.
+#[cfg(feature = "float-hash")]
+#[derive(Copy, Clone, Debug)]
+pub struct FloatHash<T>(pub T);
+
+/// The bit pattern of a float with `-0.0` and NaN mapped to a single
+/// representative, as used by `FloatHash`.
+#[cfg(feature = "float-hash")]
+pub(crate) trait CanonicalBits {
+    fn canonical_bits(self) -> u64;
+}
+
+#[cfg(feature = "float-hash")]
+impl CanonicalBits for f32 {
+    #[inline]
+    fn canonical_bits(self) -> u64 {
+        if self == 0.0 {
+            0
+        } else if self.is_nan() {
+            u64::from(f32::NAN.to_bits())
+        } else {
+            u64::from(self.to_bits())
+        }
+    }
+}
+
+#[cfg(feature = "float-hash")]
+impl CanonicalBits for f64 {
+    #[inline]
+    fn canonical_bits(self) -> u64 {
+        if self == 0.0 {
+            0
+        } else if self.is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            self.to_bits()
+        }
+    }
+}
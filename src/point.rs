@@ -18,13 +18,17 @@
 //! not have a fixed position.
 
 use num_traits::{Bounded, Float, NumCast};
+use std::convert::TryFrom;
 use std::fmt;
 use std::mem;
 use std::ops::*;
 
 use structure::*;
 
+use angle::Rad;
 use approx;
+use conv::SliceLengthError;
+use interpolate::saturate;
 use num::{BaseFloat, BaseNum};
 use vector::{Vector1, Vector2, Vector3, Vector4};
 use crate::vector::impl_vector_egui;
@@ -65,17 +69,66 @@ pub struct Point3<S> {
     pub z: S,
 }
 
+impl<S: BaseFloat> Point2<S> {
+    /// Rotate the point counter-clockwise by `angle` around `pivot`.
+    #[inline]
+    pub fn rotate_around<A: Into<Rad<S>>>(self, angle: A, pivot: Point2<S>) -> Point2<S> {
+        pivot + (self - pivot).rotate(angle)
+    }
+}
+
 impl<S: BaseNum> Point3<S> {
+    /// Create a point from homogeneous coordinates, dividing `x`, `y` and `z`
+    /// by `w`.
+    ///
+    /// A `w` of zero represents a point at infinity, which has no Cartesian
+    /// equivalent. For floating point scalars the result is then infinite
+    /// or NaN, and for integers the division panics. Use
+    /// `Vector4::homogenize` to handle this case.
     #[inline]
     pub fn from_homogeneous(v: Vector4<S>) -> Point3<S> {
         let e = v.truncate() * (S::one() / v.w);
         Point3::new(e.x, e.y, e.z) //FIXME
     }
 
+    /// Convert the point to homogeneous coordinates, with a `w` of one.
     #[inline]
     pub fn to_homogeneous(self) -> Vector4<S> {
         Vector4::new(self.x, self.y, self.z, S::one())
     }
+
+    /// Scale the point non-uniformly about the origin, multiplying each
+    /// coordinate by the matching component of `scale`.
+    #[inline]
+    pub fn scale_by(self, scale: Vector3<S>) -> Point3<S> {
+        Point3::new(self.x * scale.x, self.y * scale.y, self.z * scale.z)
+    }
+}
+
+impl<S: BaseFloat> Point3<S> {
+    /// The distance from the point to the infinite line through `a` and `b`.
+    ///
+    /// If `a` and `b` coincide, this is the distance to `a`.
+    pub fn distance_to_line(self, a: Point3<S>, b: Point3<S>) -> S {
+        let ab = b - a;
+        let len2 = ab.magnitude2();
+        if len2.is_zero() {
+            return self.distance(a);
+        }
+        let t = (self - a).dot(ab) / len2;
+        self.distance(a + ab * t)
+    }
+
+    /// The distance from the point to the line segment between `a` and `b`.
+    pub fn distance_to_segment(self, a: Point3<S>, b: Point3<S>) -> S {
+        let ab = b - a;
+        let len2 = ab.magnitude2();
+        if len2.is_zero() {
+            return self.distance(a);
+        }
+        let t = saturate((self - a).dot(ab) / len2);
+        self.distance(a + ab * t)
+    }
 }
 
 macro_rules! impl_point {
@@ -107,6 +160,23 @@ macro_rules! impl_point {
             }
         }
 
+        impl $PointN<f32> {
+            /// Convert the components to `f64`. This conversion is lossless.
+            #[inline]
+            pub fn to_f64(&self) -> $PointN<f64> {
+                $PointN { $($field: self.$field as f64),+ }
+            }
+        }
+
+        impl $PointN<f64> {
+            /// Convert the components to `f32`, rounding to the nearest
+            /// representable value.
+            #[inline]
+            pub fn to_f32(&self) -> $PointN<f32> {
+                $PointN { $($field: self.$field as f32),+ }
+            }
+        }
+
         /// The short constructor.
         #[inline]
         pub const fn $constructor<S>($($field: S),+) -> $PointN<S> {
@@ -164,6 +234,71 @@ macro_rules! impl_point {
             }
         }
 
+        impl<S: BaseNum> $PointN<S> {
+            /// The squared distance between two points.
+            ///
+            /// This forwards to `MetricSpace::distance2`, so the trait does not
+            /// need to be imported.
+            #[inline]
+            pub fn distance_squared(self, other: $PointN<S>) -> S {
+                MetricSpace::distance2(self, other)
+            }
+
+            /// Component-wise absolute difference, `|self - other|`.
+            ///
+            /// The smaller value is always subtracted from the larger one, so
+            /// this does not underflow for unsigned scalars.
+            #[inline]
+            pub fn abs_diff(self, other: $PointN<S>) -> $PointN<S> {
+                $PointN::new($(if self.$field > other.$field { self.$field - other.$field } else { other.$field - self.$field }),+)
+            }
+
+            /// Component-wise absolute difference from a scalar,
+            /// `|self - scalar|`.
+            #[inline]
+            pub fn abs_diff_scalar(self, scalar: S) -> $PointN<S> {
+                $PointN::new($(if self.$field > scalar { self.$field - scalar } else { scalar - self.$field }),+)
+            }
+        }
+
+        impl<S: BaseFloat> $PointN<S> {
+            /// The distance between two points.
+            ///
+            /// This forwards to `MetricSpace::distance`, so the trait does not
+            /// need to be imported.
+            #[inline]
+            pub fn distance(self, other: $PointN<S>) -> S {
+                MetricSpace::distance(self, other)
+            }
+
+            /// The component-wise minimum of two points.
+            ///
+            /// If one of a pair of components is NaN, the other one is used, as
+            /// with `Float::min`. This keeps a single NaN from contaminating a
+            /// running minimum, such as when growing a bounding box.
+            #[inline]
+            pub fn partial_min(self, other: $PointN<S>) -> $PointN<S> {
+                $PointN::new($(self.$field.min(other.$field)),+)
+            }
+
+            /// The component-wise maximum of two points.
+            ///
+            /// If one of a pair of components is NaN, the other one is used, as
+            /// with `Float::max`.
+            #[inline]
+            pub fn partial_max(self, other: $PointN<S>) -> $PointN<S> {
+                $PointN::new($(self.$field.max(other.$field)),+)
+            }
+
+            /// Divide each coordinate by `s`, by multiplying with `1 / s`.
+            ///
+            /// See `div_scalar_fast` on vectors for the accuracy trade-off.
+            #[inline]
+            pub fn div_scalar_fast(self, s: S) -> $PointN<S> {
+                self * (S::one() / s)
+            }
+        }
+
         impl<S: BaseNum> EuclideanSpace for $PointN<S> {
             type Scalar = S;
             type Diff = $VectorN<S>;
@@ -291,6 +426,15 @@ macro_rules! impl_point {
             #[inline] fn rem_assign_element_wise(&mut self, rhs: $PointN<S>) { $(self.$field %= rhs.$field);+ }
         }
 
+        impl<S: BaseNum> CheckedElementWise for $PointN<S> {
+            #[inline] fn checked_div_element_wise(self, rhs: $PointN<S>) -> Option<$PointN<S>> {
+                if $(rhs.$field.is_zero())||+ { None } else { Some(self.div_element_wise(rhs)) }
+            }
+            #[inline] fn checked_rem_element_wise(self, rhs: $PointN<S>) -> Option<$PointN<S>> {
+                if $(rhs.$field.is_zero())||+ { None } else { Some(self.rem_element_wise(rhs)) }
+            }
+        }
+
         impl<S: BaseNum> ElementWise<S> for $PointN<S> {
             #[inline] fn add_element_wise(self, rhs: S) -> $PointN<S> { $PointN::new($(self.$field + rhs),+) }
             #[inline] fn sub_element_wise(self, rhs: S) -> $PointN<S> { $PointN::new($(self.$field - rhs),+) }
@@ -305,6 +449,15 @@ macro_rules! impl_point {
             #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
         }
 
+        impl<S: BaseNum> CheckedElementWise<S> for $PointN<S> {
+            #[inline] fn checked_div_element_wise(self, rhs: S) -> Option<$PointN<S>> {
+                if rhs.is_zero() { None } else { Some(self.div_element_wise(rhs)) }
+            }
+            #[inline] fn checked_rem_element_wise(self, rhs: S) -> Option<$PointN<S>> {
+                if rhs.is_zero() { None } else { Some(self.rem_element_wise(rhs)) }
+            }
+        }
+
         impl_scalar_ops!($PointN<usize> { $($field),+ });
         impl_scalar_ops!($PointN<u8> { $($field),+ });
         impl_scalar_ops!($PointN<u16> { $($field),+ });
@@ -323,6 +476,7 @@ macro_rules! impl_point {
         impl_index_operators!($PointN<S>, $n, [S], RangeTo<usize>);
         impl_index_operators!($PointN<S>, $n, [S], RangeFrom<usize>);
         impl_index_operators!($PointN<S>, $n, [S], RangeFull);
+        impl_lexicographic_ord!($PointN<S>, $n);
     }
 }
 
@@ -344,6 +498,10 @@ impl_point!(Point1 { x }, Vector1, 1, point1);
 impl_point!(Point2 { x, y }, Vector2, 2, point2);
 impl_point!(Point3 { x, y, z }, Vector3, 3, point3);
 
+impl_axis_index_operators!(Point1<S> { X: x }, Axis1);
+impl_axis_index_operators!(Point2<S> { X: x, Y: y }, Axis2);
+impl_axis_index_operators!(Point3<S> { X: x, Y: y, Z: z }, Axis3);
+
 impl<S: Copy> Point1<S> {
     impl_swizzle_functions!(Point1, Point2, Point3, S, x);
 }
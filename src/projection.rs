@@ -21,6 +21,7 @@ use structure::Angle;
 use angle::Rad;
 use matrix::Matrix4;
 use num::BaseFloat;
+use point::Point3;
 
 /// Create a perspective projection matrix.
 ///
@@ -42,6 +43,53 @@ pub fn perspective<S: BaseFloat, A: Into<Rad<S>>>(
     .into()
 }
 
+/// Create a perspective projection matrix with the far plane at infinity.
+///
+/// This is the limit of [`perspective`] as `far` goes to infinity. Depth uses
+/// the OpenGL convention: the near plane maps to `-1` in normalized device
+/// coordinates, and depth approaches `+1` as the distance from the eye grows,
+/// without ever reaching it.
+///
+/// [`perspective`]: fn.perspective.html
+pub fn perspective_infinite<S: BaseFloat, A: Into<Rad<S>>>(
+    fovy: A,
+    aspect: S,
+    near: S,
+) -> Matrix4<S> {
+    let fovy = fovy.into();
+    assert!(
+        fovy > Rad::zero(),
+        "The vertical field of view cannot be below zero, found: {:?}",
+        fovy
+    );
+    assert!(
+        fovy < Rad::turn_div_2(),
+        "The vertical field of view cannot be greater than a half turn, found: {:?}",
+        fovy
+    );
+    assert!(
+        abs_diff_ne!(aspect.abs(), S::zero()),
+        "The absolute aspect ratio cannot be zero, found: {:?}",
+        aspect.abs()
+    );
+    assert!(
+        near > S::zero(),
+        "The near plane distance cannot be below zero, found: {:?}",
+        near
+    );
+
+    let two: S = cast(2).unwrap();
+    let f = Rad::cot(fovy / two);
+
+    #[cfg_attr(rustfmt, rustfmt_skip)]
+    Matrix4::new(
+        f / aspect, S::zero(), S::zero(), S::zero(),
+        S::zero(), f, S::zero(), S::zero(),
+        S::zero(), S::zero(), -S::one(), -S::one(),
+        S::zero(), S::zero(), -two * near, S::zero(),
+    )
+}
+
 /// Create a perspective matrix from a view frustum.
 ///
 /// This is the equivalent of the now deprecated [`glFrustum`] function.
@@ -76,6 +124,45 @@ pub fn ortho<S: BaseFloat>(left: S, right: S, bottom: S, top: S, near: S, far: S
     .into()
 }
 
+/// Create an orthographic projection matrix centered on the view axis.
+///
+/// The view volume spans `[-width / 2, width / 2]` horizontally and
+/// `[-height / 2, height / 2]` vertically. This is equivalent to calling
+/// [`ortho`] with symmetric bounds, which is the common case for 2D and UI
+/// rendering.
+///
+/// [`ortho`]: fn.ortho.html
+pub fn ortho_symmetric<S: BaseFloat>(width: S, height: S, near: S, far: S) -> Matrix4<S> {
+    let two: S = cast(2).unwrap();
+    let (half_width, half_height) = (width / two, height / two);
+    ortho(
+        -half_width,
+        half_width,
+        -half_height,
+        half_height,
+        near,
+        far,
+    )
+}
+
+/// Transform a point from normalized device coordinates back to world space.
+///
+/// This is the equivalent of the [`gluUnProject`] function, minus the
+/// viewport mapping. `inverse_view_proj` is the inverse of the combined
+/// `projection * view` matrix. Using the OpenGL conventions of the
+/// projections in this module, `ndc` is expected to lie in the cube
+/// `[-1, 1]³`, where a `z` of `-1` is on the near plane and `1` on the far
+/// plane.
+///
+/// The result is found by transforming the homogeneous point `(ndc, 1)` and
+/// dividing by its `w` component. Points whose `w` becomes zero, e.g. the
+/// far plane of `perspective_infinite`, have no finite position.
+///
+/// [`gluUnProject`]: https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/gluUnProject.xml
+pub fn unproject<S: BaseFloat>(ndc: Point3<S>, inverse_view_proj: &Matrix4<S>) -> Point3<S> {
+    Point3::from_homogeneous(inverse_view_proj * ndc.to_homogeneous())
+}
+
 /// A perspective projection based on a vertical field-of-view angle.
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -19,7 +19,7 @@ use std::ops::*;
 use num_traits::{cast, NumCast};
 #[cfg(feature = "rand")]
 use rand::{
-    distributions::{Distribution, Standard},
+    distr::{Distribution, StandardUniform},
     Rng,
 };
 
@@ -28,6 +28,7 @@ use structure::*;
 use angle::Rad;
 use approx;
 use euler::Euler;
+use interpolate::saturate;
 use matrix::{Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::Point3;
@@ -38,6 +39,9 @@ use vector::Vector3;
 #[cfg(feature = "mint")]
 use mint;
 
+#[cfg(feature = "float-hash")]
+use num::{CanonicalBits, FloatHash};
+
 /// A [quaternion](https://en.wikipedia.org/wiki/Quaternion) in scalar/vector
 /// form.
 ///
@@ -72,6 +76,9 @@ impl<S: BaseFloat> Quaternion<S> {
     ///
     /// Return the closest rotation that turns `src` vector into `dst`.
     ///
+    /// If either vector is zero there is no rotation between them, and the
+    /// identity is returned. Use `try_from_arc` to detect this case.
+    ///
     /// - [Related StackOverflow question](http://stackoverflow.com/questions/1171849/finding-quaternion-representing-the-rotation-from-one-vector-to-another)
     /// - [Ogre implementation for normalized vectors](https://bitbucket.org/sinbad/ogre/src/9db75e3ba05c/OgreMain/include/OgreVector3.h?fileviewer=file-view-default#cl-651)
     pub fn from_arc(
@@ -79,11 +86,32 @@ impl<S: BaseFloat> Quaternion<S> {
         dst: Vector3<S>,
         fallback: Option<Vector3<S>>,
     ) -> Quaternion<S> {
-        let mag_avg = (src.magnitude2() * dst.magnitude2()).sqrt();
+        Quaternion::try_from_arc(src, dst, fallback).unwrap_or_else(Quaternion::one)
+    }
+
+    /// Construct a new quaternion as a closest arc between two vectors, or
+    /// `None` if either vector is zero.
+    ///
+    /// See `from_arc` for details.
+    pub fn try_from_arc(
+        src: Vector3<S>,
+        dst: Vector3<S>,
+        fallback: Option<Vector3<S>>,
+    ) -> Option<Quaternion<S>> {
+        let src_mag2 = src.magnitude2();
+        let dst_mag2 = dst.magnitude2();
+        if src_mag2.is_zero() || dst_mag2.is_zero() {
+            return None;
+        }
+
+        // normalize up front, so that the products below cannot underflow for
+        // very short vectors
+        let src = src / src_mag2.sqrt();
+        let dst = dst / dst_mag2.sqrt();
         let dot = src.dot(dst);
-        if ulps_eq!(dot, &mag_avg) {
+        Some(if ulps_eq!(dot, &S::one()) {
             Quaternion::<S>::one()
-        } else if ulps_eq!(dot, &-mag_avg) {
+        } else if ulps_eq!(dot, &-S::one()) {
             let axis = fallback.unwrap_or_else(|| {
                 let mut v = Vector3::unit_x().cross(src);
                 if ulps_eq!(v, &Zero::zero()) {
@@ -93,8 +121,128 @@ impl<S: BaseFloat> Quaternion<S> {
             });
             Quaternion::from_axis_angle(axis, Rad::turn_div_2())
         } else {
-            Quaternion::from_sv(mag_avg + dot, src.cross(dst)).normalize()
+            Quaternion::from_sv(S::one() + dot, src.cross(dst)).normalize()
+        })
+    }
+
+    /// Construct a new quaternion as a closest arc between two vectors,
+    /// using `preferred_axis` to resolve the anti-parallel case.
+    ///
+    /// When `src` and `dst` point in opposite directions, the rotation is
+    /// a half turn about the component of `preferred_axis` orthogonal to
+    /// `src`. If that component is zero, this behaves like `from_arc` with
+    /// no fallback.
+    pub fn rotation_between_axis(
+        src: Vector3<S>,
+        dst: Vector3<S>,
+        preferred_axis: Vector3<S>,
+    ) -> Quaternion<S> {
+        let axis = preferred_axis - src * (preferred_axis.dot(src) / src.magnitude2());
+        let fallback = if ulps_eq!(axis, &Zero::zero()) {
+            None
+        } else {
+            Some(axis.normalize())
+        };
+        Quaternion::from_arc(src, dst, fallback)
+    }
+
+    /// Create a rotation that maps the local `forward_axis` onto `dir`, and
+    /// the local `up_axis` towards `up`.
+    ///
+    /// Unlike `Rotation::look_at`, which uses a fixed convention, this lets
+    /// the caller choose their own local axes, for example `-Z` forward and
+    /// `+Y` up. Neither pair of vectors has to be normalized or orthogonal,
+    /// but they must not be parallel.
+    pub fn look_at_with(
+        dir: Vector3<S>,
+        up: Vector3<S>,
+        forward_axis: Vector3<S>,
+        up_axis: Vector3<S>,
+    ) -> Quaternion<S> {
+        fn frame<S: BaseFloat>(forward: Vector3<S>, up: Vector3<S>) -> Matrix3<S> {
+            let forward = forward.normalize();
+            let side = up.cross(forward).normalize();
+            let up = forward.cross(side);
+            Matrix3::from_cols(side, up, forward)
+        }
+
+        let world = frame(dir, up);
+        let local = frame(forward_axis, up_axis);
+        (world * local.transpose()).into()
+    }
+
+    /// Create a rotation from roll, pitch and yaw angles.
+    ///
+    /// The angles map to the axes as roll around `x`, pitch around `y` and
+    /// yaw around `z`, and are applied in the same XYZ order as `Euler`. This
+    /// is equivalent to `Quaternion::from(Euler::new(roll, pitch, yaw))`.
+    #[inline]
+    pub fn from_euler_angles<A: Into<Rad<S>>>(roll: A, pitch: A, yaw: A) -> Quaternion<S> {
+        Quaternion::from(Euler::new(roll.into(), pitch.into(), yaw.into()))
+    }
+
+    /// The angle of the shortest rotation between two unit quaternions.
+    ///
+    /// This accounts for the double cover of rotations by quaternions, so `q`
+    /// and `-q` are zero apart. Note that this differs from
+    /// `InnerSpace::angle`, which treats the quaternions as 4D vectors.
+    pub fn angle_to(self, other: Quaternion<S>) -> Rad<S> {
+        let dot = self.dot(other).abs().min(S::one());
+        Rad::acos(dot) * (S::one() + S::one())
+    }
+
+    /// The shortest rotation from the orientation `self` to `target`, i.e.
+    /// `target * self.conjugate()`, for unit quaternions.
+    ///
+    /// The result is negated if needed so that it rotates by at most half a
+    /// turn. Applying it to `self` therefore yields either `target` or
+    /// `-target`, which represent the same orientation.
+    pub fn rotation_to(self, target: Quaternion<S>) -> Quaternion<S> {
+        let delta = target * self.conjugate();
+        if delta.s < S::zero() {
+            -delta
+        } else {
+            delta
+        }
+    }
+
+    /// Convert the quaternion to a 3 x 3 rotation matrix, without assuming
+    /// that it is normalized.
+    ///
+    /// `Matrix3::from` expects a unit quaternion, and scales and shears the
+    /// result otherwise. This divides by the squared magnitude instead, so
+    /// the result is a proper rotation for any non-zero quaternion, for
+    /// example one that has drifted after many multiplications. The zero
+    /// quaternion gives the identity.
+    pub fn to_rotation_matrix(&self) -> Matrix3<S> {
+        let mag2 = self.magnitude2();
+        if mag2.is_zero() {
+            return Matrix3::identity();
         }
+        let two_over_mag2 = (S::one() + S::one()) / mag2;
+
+        let x2 = self.v.x * two_over_mag2;
+        let y2 = self.v.y * two_over_mag2;
+        let z2 = self.v.z * two_over_mag2;
+
+        let xx2 = x2 * self.v.x;
+        let xy2 = x2 * self.v.y;
+        let xz2 = x2 * self.v.z;
+
+        let yy2 = y2 * self.v.y;
+        let yz2 = y2 * self.v.z;
+        let zz2 = z2 * self.v.z;
+
+        let sy2 = y2 * self.s;
+        let sz2 = z2 * self.s;
+        let sx2 = x2 * self.s;
+
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        Matrix3::new(
+            S::one() - yy2 - zz2, xy2 + sz2, xz2 - sy2,
+            xy2 - sz2, S::one() - xx2 - zz2, yz2 + sx2,
+            xz2 + sy2, yz2 - sx2, S::one() - xx2 - yy2,
+        )
     }
 
     /// The conjugate of the quaternion.
@@ -132,7 +280,48 @@ impl<S: BaseFloat> Quaternion<S> {
     ///
     /// - [Understanding Slerp, Then Not Using It](http://number-none.com/product/Understanding%20Slerp,%20Then%20Not%20Using%20It/)
     /// - [Arcsynthesis OpenGL tutorial](https://www.roiatalla.com/public/arcsynthesis/html/Positioning/Tut08%20Interpolation.html)
-    pub fn slerp(self, mut other: Quaternion<S>, amount: S) -> Quaternion<S> {
+    pub fn slerp(self, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let (other, theta) = self.slerp_path(other);
+        self.slerp_step(other, theta, amount)
+    }
+
+    /// Spherical Linear Interpolation, with `amount` clamped into `[0, 1]`
+    ///
+    /// `slerp` extrapolates beyond the two rotations when `amount` is outside
+    /// of `[0, 1]`. This is the safe default when that is not wanted, for
+    /// example when `amount` comes from a timer that can overshoot.
+    #[inline]
+    pub fn slerp_clamped(self, other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        self.slerp(other, saturate(amount))
+    }
+
+    /// Spherical Linear Interpolation at many points along the same path
+    ///
+    /// Writes `from.slerp(to, ts[i])` to `out[i]` for every sample, but only
+    /// computes the angle between the quaternions once, which amortizes the
+    /// cost of `acos` when sampling a path densely, e.g. when baking
+    /// animation tracks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ts` and `out` have different lengths.
+    pub fn slerp_many(from: Quaternion<S>, to: Quaternion<S>, ts: &[S], out: &mut [Quaternion<S>]) {
+        assert_eq!(
+            ts.len(),
+            out.len(),
+            "`ts` and `out` must have the same length"
+        );
+
+        let (to, theta) = from.slerp_path(to);
+        for (q, &t) in out.iter_mut().zip(ts) {
+            *q = from.slerp_step(to, theta, t);
+        }
+    }
+
+    /// The setup shared by `slerp` and `slerp_many`: `other` flipped onto
+    /// the shortest path, and the angle between the quaternions, or `None` if
+    /// they are close enough together to use `nlerp`.
+    fn slerp_path(self, mut other: Quaternion<S>) -> (Quaternion<S>, Option<Rad<S>>) {
         let mut dot = self.dot(other);
         let dot_threshold: S = cast(0.9995f64).unwrap();
 
@@ -143,18 +332,84 @@ impl<S: BaseFloat> Quaternion<S> {
 
         // if quaternions are close together use `nlerp`
         if dot > dot_threshold {
-            self.nlerp(other, amount)
+            (other, None)
         } else {
             // stay within the domain of acos()
             let robust_dot = dot.min(S::one()).max(-S::one());
 
-            let theta = Rad::acos(robust_dot);
+            (other, Some(Rad::acos(robust_dot)))
+        }
+    }
+
+    /// Interpolate by `amount` along a path computed by `slerp_path`.
+    fn slerp_step(self, other: Quaternion<S>, theta: Option<Rad<S>>, amount: S) -> Quaternion<S> {
+        match theta {
+            None => self.nlerp(other, amount),
+            Some(theta) => {
+                let scale1 = Rad::sin(theta * (S::one() - amount));
+                let scale2 = Rad::sin(theta * amount);
+
+                (self * scale1 + other * scale2).normalize()
+            }
+        }
+    }
+
+    /// Spherical Linear Interpolation, without the `nlerp` shortcut
+    ///
+    /// Unlike `slerp`, this always takes the trigonometric path, even when
+    /// the quaternions are close together, so the angular velocity is exactly
+    /// constant over `amount`. Both quaternions should be normalized first.
+    ///
+    /// This takes the shortest path, so if the quaternions have a negative
+    /// dot product, the interpolation will be between `self` and `-other`.
+    pub fn slerp_exact(self, mut other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let mut dot = self.dot(other);
+
+        if dot < S::zero() {
+            other = -other;
+            dot = -dot;
+        }
+
+        // stay within the domain of acos()
+        let theta = Rad::acos(dot.min(S::one()));
+        if theta.0.is_zero() {
+            return self;
+        }
 
-            let scale1 = Rad::sin(theta * (S::one() - amount));
-            let scale2 = Rad::sin(theta * amount);
+        let scale1 = Rad::sin(theta * (S::one() - amount));
+        let scale2 = Rad::sin(theta * amount);
+
+        (self * scale1 + other * scale2) / Rad::sin(theta)
+    }
 
-            (self * scale1 + other * scale2).normalize()
+    /// Spherical Linear Interpolation along the long path
+    ///
+    /// This is the complement of `slerp`: if the quaternions have a positive
+    /// dot product, the interpolation will be between `self` and `-other`,
+    /// so the rotation goes the long way around, by more than half a turn.
+    /// Both quaternions should be normalized first.
+    ///
+    /// If `self` and `other` describe the same orientation, this does a
+    /// full spin around an arbitrary axis.
+    pub fn slerp_long(self, mut other: Quaternion<S>, amount: S) -> Quaternion<S> {
+        let mut dot = self.dot(other);
+
+        if dot > S::zero() {
+            other = -other;
+            dot = -dot;
+        }
+
+        // the direction from `self` towards `other` on the great circle
+        let mut ortho = other - self * dot;
+        if ulps_eq!(ortho, &Quaternion::zero()) {
+            let (s, v) = (self.s, self.v);
+            ortho = Quaternion::new(-v.x, s, -v.z, v.y);
         }
+        let ortho = ortho.normalize();
+
+        // stay within the domain of acos()
+        let theta = Rad::acos(dot.max(-S::one())) * amount;
+        self * Rad::cos(theta) + ortho * Rad::sin(theta)
     }
 
     pub fn is_finite(&self) -> bool {
@@ -343,6 +598,10 @@ impl_operator!(<S: BaseFloat> Mul<Quaternion<S> > for Quaternion<S> {
     }
 });
 
+impl_assignment_operator!(<S: BaseFloat> MulAssign<Quaternion<S> > for Quaternion<S> {
+    fn mul_assign(&mut self, other) { *self = *self * other; }
+});
+
 macro_rules! impl_scalar_mul {
     ($S:ident) => {
         impl_operator!(Mul<Quaternion<$S>> for $S {
@@ -651,16 +910,45 @@ index_operators!(S, [S], RangeTo<usize>);
 index_operators!(S, [S], RangeFrom<usize>);
 index_operators!(S, [S], RangeFull);
 
+/// Samples each component independently.
+///
+/// Note that this does **not** produce a uniformly distributed rotation, or
+/// even a unit quaternion. Use `Quaternion::from_rng_uniform` for that.
 #[cfg(feature = "rand")]
-impl<S> Distribution<Quaternion<S>> for Standard
+impl<S> Distribution<Quaternion<S>> for StandardUniform
 where
-    Standard: Distribution<S>,
-    Standard: Distribution<Vector3<S>>,
+    StandardUniform: Distribution<S>,
+    StandardUniform: Distribution<Vector3<S>>,
     S: BaseNum,
 {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<S> {
-        Quaternion::from_sv(rng.gen(), rng.gen())
+        Quaternion::from_sv(rng.random(), rng.random())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: BaseFloat> Quaternion<S>
+where
+    StandardUniform: Distribution<S>,
+{
+    /// Sample a unit quaternion representing a uniformly distributed random
+    /// rotation.
+    ///
+    /// This uses Ken Shoemake's subgroup algorithm, from
+    /// [Uniform Random Rotations](https://doi.org/10.1016/B978-0-08-050755-2.50036-1)
+    /// in Graphics Gems III.
+    pub fn from_rng_uniform<R: Rng + ?Sized>(rng: &mut R) -> Quaternion<S> {
+        let u1: S = rng.random();
+        let u2: S = rng.random();
+        let u3: S = rng.random();
+
+        let a = (S::one() - u1).sqrt();
+        let b = u1.sqrt();
+        let (s2, c2) = Rad::sin_cos(Rad::full_turn() * u2);
+        let (s3, c3) = Rad::sin_cos(Rad::full_turn() * u3);
+
+        Quaternion::new(b * c3, a * s2, a * c2, b * s3)
     }
 }
 
@@ -692,6 +980,9 @@ impl<S: Clone> mint::IntoMint for Quaternion<S> {
 #[cfg(feature = "bytemuck")]
 impl_bytemuck_cast!(Quaternion);
 
+#[cfg(feature = "float-hash")]
+impl_float_hash!(Quaternion { s, v.x, v.y, v.z });
+
 #[cfg(feature = "egui-probe")]
 impl<S:egui_probe::EguiProbe> egui_probe::EguiProbe for Quaternion<S>{
         fn probe(&mut self, ui: &mut egui_probe::egui::Ui, _style: &egui_probe::Style) -> egui_probe::egui::Response {
@@ -717,6 +1008,7 @@ impl<S:BaseFloat> Default for Quaternion<S> {
 
 #[cfg(test)]
 mod tests {
+    use angle::Deg;
     use quaternion::*;
     use vector::*;
 
@@ -794,6 +1086,37 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_from_rng_uniform() {
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rotation::Rotation;
+        use structure::*;
+
+        let mut rng = SmallRng::seed_from_u64(0x5eed);
+        let n = 20_000;
+        let mut octants = [0usize; 8];
+        let mut sum = Vector3::new(0.0f64, 0.0, 0.0);
+        for _ in 0..n {
+            let q: Quaternion<f64> = Quaternion::from_rng_uniform(&mut rng);
+            assert_relative_eq!(q.magnitude(), 1.0, epsilon = 1e-12);
+
+            let v = q.rotate_vector(Vector3::unit_z());
+            let octant =
+                (v.x > 0.0) as usize | ((v.y > 0.0) as usize) << 1 | ((v.z > 0.0) as usize) << 2;
+            octants[octant] += 1;
+            sum += v;
+        }
+
+        // rotated vectors should spread evenly over the sphere
+        let expected = n as f64 / 8.0;
+        for &count in &octants {
+            assert!((count as f64 - expected).abs() < 0.1 * expected);
+        }
+        assert!((sum / n as f64).magnitude() < 0.05);
+    }
+
     #[test]
     fn test_nlerp_same() {
         let q = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
@@ -979,6 +1302,16 @@ mod tests {
         assert_ulps_eq!(expected, q.slerp(r, -1.0));
     }
 
+    #[test]
+    fn test_slerp_clamped() {
+        let q = Quaternion::from([-0.5, 0.5, 0.5, 0.5]);
+        let r = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
+
+        assert_ulps_eq!(q.slerp_clamped(r, 1.5), r);
+        assert_ulps_eq!(q.slerp_clamped(r, -0.5), q);
+        assert_eq!(q.slerp_clamped(r, 0.25), q.slerp(r, 0.25));
+    }
+
     #[test]
     fn test_slerp_regression() {
         let a = Quaternion::<f32>::new(0.00052311074, 0.9999999, 0.00014682197, -0.000016342687);
@@ -986,4 +1319,114 @@ mod tests {
 
         assert_ulps_eq!(a.slerp(b, 0.5).magnitude(), 1.0);
     }
+
+    #[test]
+    fn test_slerp_many() {
+        let ts = [-0.5, 0.0, 0.1, 0.25, 0.5, 0.9, 1.0, 1.5];
+        let pairs = [
+            // far apart
+            (
+                Quaternion::from([-0.5, 0.5, 0.5, 0.5]),
+                Quaternion::from([0.5, 0.5, 0.5, 0.5]),
+            ),
+            // negative dot product
+            (
+                Quaternion::from([-0.5, -0.5, -0.5, 0.5]),
+                Quaternion::from([0.5, 0.5, 0.5, 0.5]),
+            ),
+            // close enough for `nlerp`
+            (
+                Quaternion::from_angle_y(Deg(10.0f64)),
+                Quaternion::from_angle_y(Deg(11.0f64)),
+            ),
+        ];
+        for &(q, r) in pairs.iter() {
+            let mut out = [Quaternion::zero(); 8];
+            Quaternion::slerp_many(q, r, &ts, &mut out);
+            for (&t, &sample) in ts.iter().zip(out.iter()) {
+                assert_eq!(sample, q.slerp(r, t));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slerp_many_length_mismatch() {
+        let q = Quaternion::<f64>::one();
+        let mut out = [Quaternion::zero(); 2];
+        Quaternion::slerp_many(q, q, &[0.0, 0.5, 1.0], &mut out);
+    }
+
+    #[test]
+    fn test_slerp_exact_same() {
+        let q = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
+        assert_ulps_eq!(q, q.slerp_exact(q, 0.1234));
+    }
+
+    #[test]
+    fn test_slerp_exact_matches_slerp() {
+        let q = Quaternion::from([-0.5, -0.5, -0.5, 0.5]);
+        let r = Quaternion::from([0.5, 0.5, 0.5, 0.5]);
+        for &t in [0.0, 0.25, 0.5, 0.75, 1.0].iter() {
+            assert_ulps_eq!(q.slerp(r, t), q.slerp_exact(r, t));
+        }
+    }
+
+    #[test]
+    fn test_slerp_exact_constant_speed() {
+        // close enough together that `slerp` would fall back to `nlerp`
+        let q = Quaternion::from_angle_z(Deg(10.0f64));
+        let r = Quaternion::from_angle_z(Deg(12.0f64));
+        assert!(q.dot(r) > 0.9995);
+
+        let a = q.slerp_exact(r, 0.25);
+        let b = q.slerp_exact(r, 0.5);
+        let c = q.slerp_exact(r, 0.75);
+        let step = Rad::from(Deg(0.5f64));
+        assert_relative_eq!(q.angle_to(a), step, epsilon = 1e-12);
+        assert_relative_eq!(a.angle_to(b), step, epsilon = 1e-12);
+        assert_relative_eq!(b.angle_to(c), step, epsilon = 1e-12);
+        assert_relative_eq!(c.angle_to(r), step, epsilon = 1e-12);
+        assert_relative_eq!(b, Quaternion::from_angle_z(Deg(11.0)), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_slerp_long() {
+        let q = Quaternion::from_angle_z(Deg(10.0f64));
+        let r = Quaternion::from_angle_z(Deg(20.0f64));
+        assert_ulps_eq!(q.slerp_long(r, 0.0), q);
+        assert_ulps_eq!(q.slerp_long(r, 1.0), -r);
+
+        // the midpoint is roughly opposite the short path's midpoint
+        let mid = q.slerp_long(r, 0.5);
+        assert_ulps_eq!(mid.magnitude(), 1.0);
+        let expected = Quaternion::from_angle_z(Deg(195.0));
+        assert_relative_eq!(
+            mid.angle_to(q.slerp(r, 0.5)),
+            Rad::turn_div_2(),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(mid.angle_to(expected), Rad(0.0), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_slerp_long_negative_dot() {
+        // the short way round is -60 degrees, so the long way is +300
+        let q = Quaternion::<f64>::one();
+        let r = Quaternion::from_angle_z(Deg(300.0f64));
+        assert!(q.dot(r) < 0.0);
+        let mid = q.slerp_long(r, 0.5);
+        assert_ulps_eq!(mid, Quaternion::from_angle_z(Deg(150.0)));
+        assert_ulps_eq!(q.slerp_long(r, 1.0), r);
+    }
+
+    #[test]
+    fn test_slerp_long_same() {
+        let q = Quaternion::from_angle_x(Deg(30.0f64));
+        assert_ulps_eq!(q.slerp_long(q, 0.0), q);
+        assert_ulps_eq!(q.slerp_long(q, 1.0), -q);
+        let mid = q.slerp_long(q, 0.5);
+        assert_ulps_eq!(mid.magnitude(), 1.0);
+        assert_relative_eq!(mid.angle_to(q), Rad::turn_div_2(), epsilon = 1e-6);
+    }
 }
@@ -41,6 +41,14 @@ where
 {
     type Space: EuclideanSpace;
 
+    /// The identity rotation, which leaves every vector unchanged.
+    ///
+    /// This is the same as `One::one`, but reads better in generic code.
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+
     /// Create a rotation to a given direction with an 'up' vector.
     fn look_at(
         dir: <Self::Space as EuclideanSpace>::Diff,
@@ -174,6 +182,26 @@ impl<S: BaseFloat> Basis2<S> {
             mat: Matrix2::look_at_stable(dir, flip),
         }
     }
+
+    /// Create the rotation that turns the local `+x` axis to point along
+    /// `dir`. A zero `dir` gives the identity rotation.
+    ///
+    /// Unlike `Rotation::look_at`, this does not need an `up` vector.
+    pub fn from_direction(dir: Vector2<S>) -> Basis2<S> {
+        if ulps_eq!(dir, &Vector2::zero()) {
+            Basis2::one()
+        } else {
+            Rotation2::from_angle(Rad::atan2(dir.y, dir.x))
+        }
+    }
+
+    /// The angle of this rotation, in the range `[-π, π]`.
+    ///
+    /// This is the inverse of `Rotation2::from_angle`, up to wrapping.
+    #[inline]
+    pub fn angle(&self) -> Rad<S> {
+        Rad::atan2(self.mat.x.y, self.mat.x.x)
+    }
 }
 
 impl<S: BaseFloat> AsRef<Matrix2<S>> for Basis2<S> {
@@ -322,6 +350,25 @@ impl<S: BaseFloat> Basis3<S> {
             mat: (*quaternion).into(),
         }
     }
+
+    /// Decompose the rotation into a unit axis and an angle in the range
+    /// `[0, π]`, such that `Rotation3::from_axis_angle(axis, angle)`
+    /// reconstructs it.
+    ///
+    /// The identity rotation has no well-defined axis, so `+x` is returned
+    /// along with a zero angle.
+    pub fn to_axis_angle(&self) -> (Vector3<S>, Rad<S>) {
+        let q = Quaternion::from(self.mat).normalize();
+        // pick the representative with a non-negative scalar part, so the
+        // angle comes out in `[0, π]`
+        let q = if q.s < S::zero() { -q } else { q };
+        let sin_half = q.v.magnitude();
+        if sin_half.is_zero() {
+            return (Vector3::unit_x(), Rad::zero());
+        }
+        let angle = Rad::atan2(sin_half, q.s) * (S::one() + S::one());
+        (q.v / sin_half, angle)
+    }
 }
 
 impl<S> AsRef<Matrix3<S>> for Basis3<S> {
@@ -0,0 +1,289 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+use std::ops::*;
+
+use num_traits::cast;
+
+use structure::*;
+
+use angle::Rad;
+use approx;
+use euler::Euler;
+use matrix::Matrix3;
+use num::BaseFloat;
+use point::Point3;
+use quaternion::Quaternion;
+use rotation::{Basis3, Rotation, Rotation3};
+use vector::Vector3;
+
+/// A three-dimensional [rotor](https://en.wikipedia.org/wiki/Rotor_(mathematics)),
+/// the geometric algebra counterpart of a unit quaternion.
+///
+/// A rotor is the sum of a scalar and a bivector. The bivector components
+/// are the oriented planes `xy = e1 ∧ e2`, `yz = e2 ∧ e3` and `zx = e3 ∧ e1`.
+/// A vector is rotated by the sandwich product `R v R̃`, where `R̃` is the
+/// reverse of the rotor, and rotors are composed with the geometric product
+/// in the same order as quaternions: `(a * b).rotate_vector(v)` applies `b`
+/// first.
+///
+/// A rotation by `θ` in the plane `B` is `cos(θ/2) - sin(θ/2) B`, so a rotor
+/// has the same scalar part as the equivalent quaternion, and bivector
+/// components that are the negated vector part, with `yz`, `zx` and `xy`
+/// standing in for `x`, `y` and `z`. The `From` conversions between the two
+/// are exact.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Rotor3<S> {
+    /// The scalar part.
+    pub s: S,
+    /// The component in the `xy` plane.
+    pub xy: S,
+    /// The component in the `yz` plane.
+    pub yz: S,
+    /// The component in the `zx` plane.
+    pub zx: S,
+}
+
+impl<S> Rotor3<S> {
+    /// Construct a new rotor from a scalar and the bivector components.
+    #[inline]
+    pub const fn new(s: S, xy: S, yz: S, zx: S) -> Rotor3<S> {
+        Rotor3 { s, xy, yz, zx }
+    }
+}
+
+impl<S: BaseFloat> Rotor3<S> {
+    /// Create the rotor that rotates the unit vector `from` onto the unit
+    /// vector `to` along the shortest arc.
+    ///
+    /// This is `1 + to * from`, normalized. For opposite vectors there is no
+    /// unique plane of rotation, so a half turn in an arbitrary plane
+    /// containing `from` is returned.
+    pub fn from_rotation_between(from: Vector3<S>, to: Vector3<S>) -> Rotor3<S> {
+        // the bivector part of `to * from` is `to ∧ from`
+        let s = S::one() + from.dot(to);
+        let b = to.wedge(from);
+        if s <= S::epsilon() {
+            let mut plane = from.cross(Vector3::unit_x());
+            if ulps_eq!(plane.magnitude2(), S::zero()) {
+                plane = from.cross(Vector3::unit_y());
+            }
+            let plane = plane.normalize();
+            return Rotor3::new(S::zero(), plane.z, plane.x, plane.y);
+        }
+        Rotor3::new(s, b.z, b.x, b.y).normalize()
+    }
+
+    /// The reverse of the rotor, `R̃`, which negates the bivector part.
+    ///
+    /// For a normalized rotor this is also its inverse.
+    #[inline]
+    pub fn reverse(self) -> Rotor3<S> {
+        Rotor3::new(self.s, -self.xy, -self.yz, -self.zx)
+    }
+
+    /// The squared magnitude, `R R̃`.
+    #[inline]
+    pub fn magnitude2(self) -> S {
+        self.s * self.s + self.xy * self.xy + self.yz * self.yz + self.zx * self.zx
+    }
+
+    /// The magnitude of the rotor. This is `1` for rotors that represent a
+    /// rotation.
+    #[inline]
+    pub fn magnitude(self) -> S {
+        self.magnitude2().sqrt()
+    }
+
+    /// Scale the rotor to unit magnitude.
+    #[inline]
+    pub fn normalize(self) -> Rotor3<S> {
+        let m = S::one() / self.magnitude();
+        Rotor3::new(self.s * m, self.xy * m, self.yz * m, self.zx * m)
+    }
+
+    /// The bivector part, dualized into a vector with `yz`, `zx` and `xy` as
+    /// its `x`, `y` and `z` components.
+    #[inline]
+    fn bivector_dual(self) -> Vector3<S> {
+        Vector3::new(self.yz, self.zx, self.xy)
+    }
+}
+
+impl<S: BaseFloat> From<Quaternion<S>> for Rotor3<S> {
+    #[inline]
+    fn from(q: Quaternion<S>) -> Rotor3<S> {
+        Rotor3::new(q.s, -q.v.z, -q.v.x, -q.v.y)
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Quaternion<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Quaternion<S> {
+        Quaternion::new(r.s, -r.yz, -r.zx, -r.xy)
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Matrix3<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Matrix3<S> {
+        Quaternion::from(r).into()
+    }
+}
+
+impl<S: BaseFloat> From<Rotor3<S>> for Basis3<S> {
+    #[inline]
+    fn from(r: Rotor3<S>) -> Basis3<S> {
+        Basis3::from_quaternion(&r.into())
+    }
+}
+
+impl<A> From<Euler<A>> for Rotor3<A::Unitless>
+where
+    A: Angle + Into<Rad<<A as Angle>::Unitless>>,
+{
+    #[inline]
+    fn from(src: Euler<A>) -> Rotor3<A::Unitless> {
+        Quaternion::from(src).into()
+    }
+}
+
+impl<S: BaseFloat> One for Rotor3<S> {
+    #[inline]
+    fn one() -> Rotor3<S> {
+        Rotor3::new(S::one(), S::zero(), S::zero(), S::zero())
+    }
+}
+
+impl_operator!(<S: BaseFloat> Mul<Rotor3<S> > for Rotor3<S> {
+    fn mul(lhs, rhs) -> Rotor3<S> {{
+        // the geometric product of two scalar + bivector sums
+        let (a, b) = (lhs.bivector_dual(), rhs.bivector_dual());
+        let v = b * lhs.s + a * rhs.s - a.cross(b);
+        Rotor3::new(lhs.s * rhs.s - a.dot(b), v.z, v.x, v.y)
+    }}
+});
+
+impl_operator!(<S: BaseFloat> Mul<Vector3<S> > for Rotor3<S> {
+    fn mul(lhs, rhs) -> Vector3<S> {{
+        // the sandwich product `R v R̃`, expanded
+        let rhs = Vector3::new(rhs.x, rhs.y, rhs.z);
+        let two: S = cast(2i8).unwrap();
+        let b = lhs.bivector_dual();
+        let tmp = b.cross(rhs) - rhs * lhs.s;
+        (b.cross(tmp) * two) + rhs
+    }}
+});
+
+impl<S: BaseFloat> iter::Product<Rotor3<S>> for Rotor3<S> {
+    #[inline]
+    fn product<I: Iterator<Item = Rotor3<S>>>(iter: I) -> Rotor3<S> {
+        iter.fold(Rotor3::one(), Mul::mul)
+    }
+}
+
+impl<'a, S: 'a + BaseFloat> iter::Product<&'a Rotor3<S>> for Rotor3<S> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Rotor3<S>>>(iter: I) -> Rotor3<S> {
+        iter.fold(Rotor3::one(), Mul::mul)
+    }
+}
+
+impl<S: BaseFloat> approx::AbsDiffEq for Rotor3<S> {
+    type Epsilon = S::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> S::Epsilon {
+        S::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: S::Epsilon) -> bool {
+        S::abs_diff_eq(&self.s, &other.s, epsilon)
+            && S::abs_diff_eq(&self.xy, &other.xy, epsilon)
+            && S::abs_diff_eq(&self.yz, &other.yz, epsilon)
+            && S::abs_diff_eq(&self.zx, &other.zx, epsilon)
+    }
+}
+
+impl<S: BaseFloat> approx::RelativeEq for Rotor3<S> {
+    #[inline]
+    fn default_max_relative() -> S::Epsilon {
+        S::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: S::Epsilon, max_relative: S::Epsilon) -> bool {
+        S::relative_eq(&self.s, &other.s, epsilon, max_relative)
+            && S::relative_eq(&self.xy, &other.xy, epsilon, max_relative)
+            && S::relative_eq(&self.yz, &other.yz, epsilon, max_relative)
+            && S::relative_eq(&self.zx, &other.zx, epsilon, max_relative)
+    }
+}
+
+impl<S: BaseFloat> approx::UlpsEq for Rotor3<S> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        S::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: S::Epsilon, max_ulps: u32) -> bool {
+        S::ulps_eq(&self.s, &other.s, epsilon, max_ulps)
+            && S::ulps_eq(&self.xy, &other.xy, epsilon, max_ulps)
+            && S::ulps_eq(&self.yz, &other.yz, epsilon, max_ulps)
+            && S::ulps_eq(&self.zx, &other.zx, epsilon, max_ulps)
+    }
+}
+
+impl<S: BaseFloat> Rotation for Rotor3<S> {
+    type Space = Point3<S>;
+
+    #[inline]
+    fn look_at(dir: Vector3<S>, up: Vector3<S>) -> Rotor3<S> {
+        Quaternion::look_at(dir, up).into()
+    }
+
+    #[inline]
+    fn between_vectors(a: Vector3<S>, b: Vector3<S>) -> Rotor3<S> {
+        Rotor3::from_rotation_between(a, b)
+    }
+
+    #[inline]
+    fn rotate_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        self * vec
+    }
+
+    #[inline]
+    fn invert(&self) -> Rotor3<S> {
+        let m = S::one() / self.magnitude2();
+        let r = self.reverse();
+        Rotor3::new(r.s * m, r.xy * m, r.yz * m, r.zx * m)
+    }
+}
+
+impl<S: BaseFloat> Rotation3 for Rotor3<S> {
+    type Scalar = S;
+
+    #[inline]
+    fn from_axis_angle<A: Into<Rad<S>>>(axis: Vector3<S>, angle: A) -> Rotor3<S> {
+        // the plane of rotation is the dual of the axis
+        let (s, c) = Rad::sin_cos(angle.into() * cast(0.5f64).unwrap());
+        Rotor3::new(c, -axis.z * s, -axis.x * s, -axis.y * s)
+    }
+}
@@ -27,6 +27,81 @@ use num::{BaseFloat, BaseNum};
 
 pub use num_traits::{Bounded, Num, NumCast, One, Zero};
 
+/// A named coordinate axis of a one-dimensional vector or point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis1 {
+    /// The first axis, at index `0`.
+    X = 0,
+}
+
+/// A named coordinate axis of a two-dimensional vector or point.
+///
+/// Indexing with an axis is equivalent to indexing with its position, so
+/// `v[Axis2::Y]` is the same as `v[1]`. There is a separate axis type for
+/// each dimension, so an axis beyond the dimension of the value, such as
+/// `Axis3::Z` on a `Vector2`, does not compile.
+///
+/// ```rust
+/// use cgmath::{Axis2, Vector2};
+///
+/// let v = Vector2::new(1, 2);
+/// assert_eq!(v[Axis2::Y], 2);
+/// ```
+///
+/// ```compile_fail
+/// use cgmath::{Axis3, Vector2};
+///
+/// let v = Vector2::new(1, 2);
+/// let _ = v[Axis3::Z];
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis2 {
+    /// The first axis, at index `0`.
+    X = 0,
+    /// The second axis, at index `1`.
+    Y = 1,
+}
+
+/// A named coordinate axis of a three-dimensional vector or point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis3 {
+    /// The first axis, at index `0`.
+    X = 0,
+    /// The second axis, at index `1`.
+    Y = 1,
+    /// The third axis, at index `2`.
+    Z = 2,
+}
+
+/// A named coordinate axis of a four-dimensional vector or point.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis4 {
+    /// The first axis, at index `0`.
+    X = 0,
+    /// The second axis, at index `1`.
+    Y = 1,
+    /// The third axis, at index `2`.
+    Z = 2,
+    /// The fourth axis, at index `3`.
+    W = 3,
+}
+
+macro_rules! impl_axis_index {
+    ($($Axis:ident),+) => {
+        $(
+            impl $Axis {
+                /// The position of this axis, for use as an element index.
+                #[inline]
+                pub fn index(self) -> usize {
+                    self as usize
+                }
+            }
+        )+
+    };
+}
+
+impl_axis_index!(Axis1, Axis2, Axis3, Axis4);
+
 /// An array containing elements of type `Element`
 pub trait Array
 where
@@ -110,6 +185,14 @@ pub trait ElementWise<Rhs = Self> {
     fn rem_assign_element_wise(&mut self, rhs: Rhs);
 }
 
+/// Element-wise division and remainder that check for a zero divisor.
+pub trait CheckedElementWise<Rhs = Self>: ElementWise<Rhs> + Sized {
+    /// Element-wise division, returning `None` if any divisor is zero.
+    fn checked_div_element_wise(self, rhs: Rhs) -> Option<Self>;
+    /// Element-wise remainder, returning `None` if any divisor is zero.
+    fn checked_rem_element_wise(self, rhs: Rhs) -> Option<Self>;
+}
+
 /// Vectors that can be [added](http://mathworld.wolfram.com/VectorAddition.html)
 /// together and [multiplied](https://en.wikipedia.org/wiki/Scalar_multiplication)
 /// by scalars.
@@ -272,13 +355,26 @@ where
         Float::sqrt(self.magnitude2())
     }
 
+    /// The reciprocal of the magnitude, `1 / magnitude`.
+    ///
+    /// This is the factor that `normalize` scales by, so it can be used to
+    /// get both the unit vector and the inverse length from a single square
+    /// root.
+    #[inline]
+    fn magnitude_recip(self) -> Self::Scalar
+    where
+        Self::Scalar: Float,
+    {
+        Self::Scalar::one() / self.magnitude()
+    }
+
     /// Returns a vector with the same direction, but with a magnitude of `1`.
     #[inline]
     fn normalize(self) -> Self
     where
         Self::Scalar: Float,
     {
-        self.normalize_to(Self::Scalar::one())
+        self * self.magnitude_recip()
     }
 
     /// Returns a vector with the same direction and a given magnitude.
@@ -289,6 +385,32 @@ where
     {
         self * (magnitude / self.magnitude())
     }
+
+    /// Returns the vector scaled down to a magnitude of `max` if it is longer
+    /// than that, or the vector unchanged otherwise.
+    #[inline]
+    fn clamp_magnitude(self, max: Self::Scalar) -> Self
+    where
+        Self::Scalar: Float,
+    {
+        if self.magnitude2() > max * max {
+            self.normalize_to(max)
+        } else {
+            self
+        }
+    }
+
+    /// Test if the vector has unit length, i.e. if its squared magnitude is
+    /// within `epsilon` of one.
+    ///
+    /// Use `Zero::is_zero` to test for the zero vector.
+    #[inline]
+    fn is_normalized(&self, epsilon: Self::Scalar) -> bool
+    where
+        Self::Scalar: Float,
+    {
+        (self.magnitude2() - Self::Scalar::one()).abs() <= epsilon
+    }
 }
 
 /// Points in a [Euclidean space](https://en.wikipedia.org/wiki/Euclidean_space)
@@ -391,6 +513,21 @@ where
         self + (other - self) / (Self::Scalar::one() + Self::Scalar::one())
     }
 
+    /// Returns the result of linearly interpolating the point
+    /// towards `other` by the specified amount.
+    ///
+    /// ```rust
+    /// use cgmath::prelude::*;
+    /// use cgmath::Point2;
+    ///
+    /// let p = Point2::new(0.0, 2.0).lerp(Point2::new(4.0, 0.0), 0.25);
+    /// assert_eq!(p, Point2::new(1.0, 1.5));
+    /// ```
+    #[inline]
+    fn lerp(self, other: Self, amount: Self::Scalar) -> Self {
+        self + ((other - self) * amount)
+    }
+
     /// Returns the average position of all points in the slice.
     ///
     /// ```rust
@@ -482,6 +619,19 @@ where
     /// Get a row from this matrix by-value.
     fn row(&self, r: usize) -> Self::Row;
 
+    /// Replace a row in the array, returning the old row.
+    ///
+    /// As the matrix is stored in column-major order, this scatters the
+    /// elements of `src` across the columns.
+    #[inline]
+    fn replace_row(&mut self, r: usize, src: Self::Row) -> Self::Row {
+        let old = self.row(r);
+        for c in 0..Self::Row::len() {
+            self[c][r] = src[c];
+        }
+        old
+    }
+
     /// Swap two rows of this array.
     fn swap_rows(&mut self, a: usize, b: usize);
     /// Swap two columns of this array.
@@ -537,6 +687,10 @@ where
     }
 
     /// Transpose this matrix in-place.
+    ///
+    /// This swaps the elements on either side of the diagonal directly,
+    /// without building a new matrix, which makes it a cheaper choice than
+    /// `*m = m.transpose()` when updating many matrices.
     fn transpose_self(&mut self);
     /// Take the determinant of this matrix.
     fn determinant(&self) -> Self::Scalar;
@@ -550,6 +704,23 @@ where
         self.diagonal().sum()
     }
 
+    /// Raise this matrix to the integer power `n`, using exponentiation by
+    /// squaring. `m.pow(0)` is the identity matrix.
+    fn pow(&self, mut n: u32) -> Self {
+        let mut base = *self;
+        let mut result = Self::identity();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+
     /// Invert this matrix, returning a new matrix. `m.mul_m(m.invert())` is
     /// the identity matrix. Returns `None` if this matrix is not invertible
     /// (has a determinant of zero).
@@ -564,6 +735,30 @@ where
         ulps_ne!(self.determinant(), &Self::Scalar::zero())
     }
 
+    /// Test if this matrix is invertible, treating determinants with an
+    /// absolute value of at most `epsilon` as zero.
+    #[inline]
+    fn is_invertible_with_epsilon(&self, epsilon: Self::Scalar) -> bool
+    where
+        Self::Scalar: BaseFloat,
+    {
+        self.determinant().abs() > epsilon
+    }
+
+    /// The sign of the determinant: `1` if the matrix preserves orientation,
+    /// `-1` if it flips it, and `0` if it is singular.
+    #[inline]
+    fn determinant_sign(&self) -> i32 {
+        let det = self.determinant();
+        if det > Self::Scalar::zero() {
+            1
+        } else if det < Self::Scalar::zero() {
+            -1
+        } else {
+            0
+        }
+    }
+
     /// Test if this matrix is the identity matrix. That is, it is diagonal
     /// and every element in the diagonal is one.
     #[inline]
@@ -574,6 +769,16 @@ where
         ulps_eq!(self, &Self::identity())
     }
 
+    /// Test if this matrix is the identity matrix, allowing every element to
+    /// differ from it by at most `epsilon`.
+    #[inline]
+    fn is_identity_with_epsilon(&self, epsilon: Self::Scalar) -> bool
+    where
+        Self: approx::AbsDiffEq<Epsilon = Self::Scalar>,
+    {
+        self.abs_diff_eq(&Self::identity(), epsilon)
+    }
+
     /// Test if this is a diagonal matrix. That is, every element outside of
     /// the diagonal is 0.
     fn is_diagonal(&self) -> bool;
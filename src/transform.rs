@@ -16,6 +16,7 @@
 use structure::*;
 
 use approx;
+use bounds::Ray3;
 use matrix::{Matrix2, Matrix3, Matrix4};
 use num::{BaseFloat, BaseNum};
 use point::{Point2, Point3};
@@ -76,6 +77,53 @@ pub struct Decomposed<V: VectorSpace, R> {
     pub disp: V,
 }
 
+impl<V: VectorSpace, R> Decomposed<V, R> {
+    /// Replace the displacement, keeping the rotation and scale.
+    #[inline]
+    pub fn with_translation(self, disp: V) -> Decomposed<V, R> {
+        Decomposed { disp, ..self }
+    }
+
+    /// Replace the rotation, keeping the displacement and scale.
+    #[inline]
+    pub fn with_rotation(self, rot: R) -> Decomposed<V, R> {
+        Decomposed { rot, ..self }
+    }
+
+    /// Replace the scale, keeping the displacement and rotation.
+    #[inline]
+    pub fn with_scale(self, scale: V::Scalar) -> Decomposed<V, R> {
+        Decomposed { scale, ..self }
+    }
+}
+
+impl<P: EuclideanSpace, R: Rotation<Space = P>> Decomposed<P::Diff, R>
+where
+    P::Scalar: BaseFloat,
+{
+    /// The identity transform, with no displacement, no rotation and a scale
+    /// of one.
+    ///
+    /// This is the same as `One::one`, and a convenient starting point for
+    /// the `with_*` builder methods.
+    #[inline]
+    pub fn identity() -> Decomposed<P::Diff, R> {
+        Decomposed::one()
+    }
+
+    /// Concatenate a sequence of transforms into a single one.
+    ///
+    /// The result is `transforms[0].concat(&transforms[1])...`, so the last
+    /// transform in the slice is applied to a point first. An empty slice
+    /// gives the identity transform.
+    pub fn concat_all(transforms: &[Decomposed<P::Diff, R>]) -> Decomposed<P::Diff, R> {
+        match transforms.split_first() {
+            Some((first, rest)) => rest.iter().fold(*first, |acc, t| acc.concat(t)),
+            None => Decomposed::one(),
+        }
+    }
+}
+
 impl<P: EuclideanSpace, R: Rotation<Space = P>> One for Decomposed<P::Diff, R>
 where
     P::Scalar: BaseFloat,
@@ -196,6 +244,32 @@ pub trait Transform3:
     Transform<Point3<<Self as Transform3>::Scalar>> + Into<Matrix4<<Self as Transform3>::Scalar>>
 {
     type Scalar: BaseNum;
+
+    /// Transform a ray, mapping its origin as a point and its direction as a
+    /// vector.
+    ///
+    /// The direction is not renormalized, so a parameter `t` along the
+    /// transformed ray refers to the image of the point at `t` along the
+    /// original ray.
+    #[inline]
+    fn transform_ray(&self, ray: &Ray3<Self::Scalar>) -> Ray3<Self::Scalar> {
+        Ray3::new(
+            self.transform_point(ray.origin),
+            self.transform_vector(ray.direction),
+        )
+    }
+
+    /// Transform a ray by the inverse of this transform, or return `None` if
+    /// the transform is not invertible.
+    ///
+    /// This is typically used to bring a world-space ray into the object space
+    /// of a model for intersection tests. Since the direction is not
+    /// renormalized, hit parameters found in object space are valid for the
+    /// original ray as well.
+    #[inline]
+    fn inverse_transform_ray(&self, ray: &Ray3<Self::Scalar>) -> Option<Ray3<Self::Scalar>> {
+        self.inverse_transform().map(|t| t.transform_ray(ray))
+    }
 }
 
 impl<S: BaseFloat, R: Rotation2<Scalar = S>> From<Decomposed<Vector2<S>, R>> for Matrix3<S> {
@@ -224,6 +298,91 @@ impl<S: BaseFloat, R: Rotation3<Scalar = S>> Transform3 for Decomposed<Vector3<S
     type Scalar = S;
 }
 
+/// A non-uniform scale along the coordinate axes.
+///
+/// This keeps per-axis scale factors apart from displacement vectors in the
+/// type system, so one can't be passed where the other is expected.
+///
+/// A scale cannot represent a look-at transformation, so this does not
+/// implement `Transform`. Combine it with other transforms by converting it
+/// to a `Matrix4`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Scale3<S>(pub Vector3<S>);
+
+impl<S: BaseFloat> Scale3<S> {
+    /// Scale a vector by the per-axis factors.
+    #[inline]
+    pub fn transform_vector(&self, vec: Vector3<S>) -> Vector3<S> {
+        vec.mul_element_wise(self.0)
+    }
+
+    /// Scale a point about the origin by the per-axis factors.
+    #[inline]
+    pub fn transform_point(&self, point: Point3<S>) -> Point3<S> {
+        Point3::from_vec(point.to_vec().mul_element_wise(self.0))
+    }
+
+    /// Combine this scale with another, giving the product of the factors.
+    #[inline]
+    pub fn concat(&self, other: &Scale3<S>) -> Scale3<S> {
+        Scale3(self.0.mul_element_wise(other.0))
+    }
+
+    /// The scale that undoes this one, or `None` if any factor is zero.
+    pub fn inverse(&self) -> Option<Scale3<S>> {
+        if ulps_eq!(self.0.x, &S::zero())
+            || ulps_eq!(self.0.y, &S::zero())
+            || ulps_eq!(self.0.z, &S::zero())
+        {
+            None
+        } else {
+            Some(Scale3(Vector3::new(
+                S::one() / self.0.x,
+                S::one() / self.0.y,
+                S::one() / self.0.z,
+            )))
+        }
+    }
+}
+
+impl<S> From<Vector3<S>> for Scale3<S> {
+    #[inline]
+    fn from(factors: Vector3<S>) -> Scale3<S> {
+        Scale3(factors)
+    }
+}
+
+impl<S> From<Scale3<S>> for Vector3<S> {
+    #[inline]
+    fn from(scale: Scale3<S>) -> Vector3<S> {
+        scale.0
+    }
+}
+
+impl<S: BaseFloat> From<Scale3<S>> for Matrix4<S> {
+    #[inline]
+    fn from(scale: Scale3<S>) -> Matrix4<S> {
+        Matrix4::from_nonuniform_scale(scale.0.x, scale.0.y, scale.0.z)
+    }
+}
+
+impl<S: BaseFloat> One for Scale3<S> {
+    #[inline]
+    fn one() -> Scale3<S> {
+        Scale3(Vector3::new(S::one(), S::one(), S::one()))
+    }
+}
+
+impl<S: BaseFloat> Mul for Scale3<S> {
+    type Output = Scale3<S>;
+
+    #[inline]
+    fn mul(self, rhs: Scale3<S>) -> Scale3<S> {
+        self.concat(&rhs)
+    }
+}
+
 impl<S: VectorSpace, R, E: BaseFloat> approx::AbsDiffEq for Decomposed<S, R>
 where
     S: approx::AbsDiffEq<Epsilon = E>,
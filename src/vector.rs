@@ -13,12 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use num_traits::{Bounded, Float, NumCast};
+use num_traits::{
+    Bounded, CheckedAdd, CheckedSub, Float, NumCast, SaturatingAdd, SaturatingSub, WrappingAdd,
+    WrappingSub,
+};
 #[cfg(feature = "rand")]
 use rand::{
-    distributions::{Distribution, Standard},
+    distr::{Distribution, StandardUniform},
     Rng,
 };
+use std::convert::TryFrom;
 use std::fmt;
 use std::iter;
 use std::mem;
@@ -28,11 +32,16 @@ use structure::*;
 
 use angle::Rad;
 use approx;
+use conv::SliceLengthError;
+use interpolate::saturate;
 use num::{BaseFloat, BaseNum};
 
 #[cfg(feature = "mint")]
 use mint;
 
+#[cfg(feature = "float-hash")]
+use num::{CanonicalBits, FloatHash};
+
 /// A 1-dimensional vector.
 ///
 /// This type is marked as `#[repr(C)]`.
@@ -117,6 +126,58 @@ macro_rules! impl_vector {
             {
                 $VectorN { $($field: f(self.$field, v2.$field)),+ }
             }
+
+            /// Construct a new vector by calling `f` with the index of each
+            /// component, in order.
+            #[inline]
+            pub fn from_fn<F>(f: F) -> $VectorN<S>
+                where F: FnMut(usize) -> S
+            {
+                let [$($field),+]: [S; $n] = ::std::array::from_fn(f);
+                $VectorN { $($field),+ }
+            }
+
+            /// Collect the components into a `Vec`, in order.
+            #[inline]
+            pub fn to_component_vec(&self) -> Vec<S>
+                where S: Clone
+            {
+                vec![$(self.$field.clone()),+]
+            }
+        }
+
+        impl $VectorN<f32> {
+            /// Convert the components to `f64`. This conversion is lossless.
+            #[inline]
+            pub fn to_f64(&self) -> $VectorN<f64> {
+                $VectorN { $($field: self.$field as f64),+ }
+            }
+
+            /// Reinterpret the bits of each component as a `u32`.
+            ///
+            /// Unlike a numeric cast, this preserves the exact bit pattern,
+            /// including for NaN, which makes it suitable for hashing or for
+            /// packing into GPU buffers.
+            #[inline]
+            pub fn to_bits(self) -> $VectorN<u32> {
+                $VectorN { $($field: self.$field.to_bits()),+ }
+            }
+
+            /// Reinterpret the bits of each component as an `f32`. This is the
+            /// inverse of `to_bits`.
+            #[inline]
+            pub fn from_bits(bits: $VectorN<u32>) -> $VectorN<f32> {
+                $VectorN { $($field: f32::from_bits(bits.$field)),+ }
+            }
+        }
+
+        impl $VectorN<f64> {
+            /// Convert the components to `f32`, rounding to the nearest
+            /// representable value.
+            #[inline]
+            pub fn to_f32(&self) -> $VectorN<f32> {
+                $VectorN { $($field: self.$field as f32),+ }
+            }
         }
 
         /// The short constructor.
@@ -139,6 +200,50 @@ macro_rules! impl_vector {
             }
         }
 
+        impl<S: WrappingAdd + WrappingSub> $VectorN<S> {
+            /// Component-wise wrapping addition.
+            #[inline]
+            pub fn wrapping_add(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.wrapping_add(&other.$field)),+)
+            }
+
+            /// Component-wise wrapping subtraction.
+            #[inline]
+            pub fn wrapping_sub(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.wrapping_sub(&other.$field)),+)
+            }
+        }
+
+        impl<S: SaturatingAdd + SaturatingSub> $VectorN<S> {
+            /// Component-wise saturating addition.
+            #[inline]
+            pub fn saturating_add(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.saturating_add(&other.$field)),+)
+            }
+
+            /// Component-wise saturating subtraction.
+            #[inline]
+            pub fn saturating_sub(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.saturating_sub(&other.$field)),+)
+            }
+        }
+
+        impl<S: CheckedAdd + CheckedSub> $VectorN<S> {
+            /// Component-wise checked addition, returning `None` if any
+            /// component overflows.
+            #[inline]
+            pub fn checked_add(self, other: $VectorN<S>) -> Option<$VectorN<S>> {
+                Some($VectorN::new($(self.$field.checked_add(&other.$field)?),+))
+            }
+
+            /// Component-wise checked subtraction, returning `None` if any
+            /// component overflows.
+            #[inline]
+            pub fn checked_sub(self, other: $VectorN<S>) -> Option<$VectorN<S>> {
+                Some($VectorN::new($(self.$field.checked_sub(&other.$field)?),+))
+            }
+        }
+
         impl<S: BaseNum> MetricSpace for $VectorN<S> {
             type Metric = S;
 
@@ -148,6 +253,106 @@ macro_rules! impl_vector {
             }
         }
 
+        impl<S: BaseNum> $VectorN<S> {
+            /// The squared distance between two vectors.
+            ///
+            /// This forwards to `MetricSpace::distance2`, so the trait does not
+            /// need to be imported.
+            #[inline]
+            pub fn distance_squared(self, other: $VectorN<S>) -> S {
+                MetricSpace::distance2(self, other)
+            }
+
+            /// Component-wise absolute difference, `|self - other|`.
+            ///
+            /// The smaller value is always subtracted from the larger one, so
+            /// this does not underflow for unsigned scalars.
+            #[inline]
+            pub fn abs_diff(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(if self.$field > other.$field { self.$field - other.$field } else { other.$field - self.$field }),+)
+            }
+
+            /// Component-wise absolute difference from a scalar,
+            /// `|self - scalar|`.
+            #[inline]
+            pub fn abs_diff_scalar(self, scalar: S) -> $VectorN<S> {
+                $VectorN::new($(if self.$field > scalar { self.$field - scalar } else { scalar - self.$field }),+)
+            }
+        }
+
+        impl<S: BaseFloat> $VectorN<S> {
+            /// The distance between two vectors.
+            ///
+            /// This forwards to `MetricSpace::distance`, so the trait does not
+            /// need to be imported.
+            #[inline]
+            pub fn distance(self, other: $VectorN<S>) -> S {
+                MetricSpace::distance(self, other)
+            }
+
+            /// The component-wise minimum of two vectors.
+            ///
+            /// If one of a pair of components is NaN, the other one is used, as
+            /// with `Float::min`. This keeps a single NaN from contaminating a
+            /// running minimum, such as when growing a bounding box.
+            #[inline]
+            pub fn partial_min(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.min(other.$field)),+)
+            }
+
+            /// The component-wise maximum of two vectors.
+            ///
+            /// If one of a pair of components is NaN, the other one is used, as
+            /// with `Float::max`.
+            #[inline]
+            pub fn partial_max(self, other: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.max(other.$field)),+)
+            }
+
+            /// Compute `self * mul + add` component-wise, with only one rounding
+            /// error per component. See `Float::mul_add`.
+            #[inline]
+            pub fn mul_add(self, mul: $VectorN<S>, add: $VectorN<S>) -> $VectorN<S> {
+                $VectorN::new($(self.$field.mul_add(mul.$field, add.$field)),+)
+            }
+
+            /// Clamp each component into the range `[0, 1]`.
+            #[inline]
+            pub fn saturate(self) -> $VectorN<S> {
+                self.map(saturate)
+            }
+
+            /// The largest absolute value of the components, i.e. the
+            /// infinity norm of the vector. NaN components are ignored.
+            #[inline]
+            pub fn max_abs(&self) -> S {
+                S::zero()$(.max(self.$field.abs()))+
+            }
+
+            /// Divide each component by `s`, by multiplying with `1 / s`.
+            ///
+            /// This trades one division per component for a single division,
+            /// which is usually faster. The result can differ from `self / s`
+            /// in the last bit, since the reciprocal is rounded before the
+            /// multiplication.
+            #[inline]
+            pub fn div_scalar_fast(self, s: S) -> $VectorN<S> {
+                self * (S::one() / s)
+            }
+
+            /// Construct a new vector, or return `None` if any of the provided
+            /// values is NaN or infinite.
+            #[inline]
+            pub fn from_finite($($field: S),+) -> Option<$VectorN<S>> {
+                let v = $VectorN::new($($field),+);
+                if v.is_finite() {
+                    Some(v)
+                } else {
+                    None
+                }
+            }
+        }
+
         impl<S: Copy> Array for $VectorN<S> {
             type Element = S;
 
@@ -251,12 +456,12 @@ macro_rules! impl_vector {
         }
 
         #[cfg(feature = "rand")]
-        impl<S> Distribution<$VectorN<S>> for Standard
-            where Standard: Distribution<S>,
+        impl<S> Distribution<$VectorN<S>> for StandardUniform
+            where StandardUniform: Distribution<S>,
                 S: BaseNum {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $VectorN<S> {
-                $VectorN { $($field: rng.gen()),+ }
+                $VectorN { $($field: rng.random()),+ }
             }
         }
 
@@ -321,6 +526,15 @@ macro_rules! impl_vector {
             #[inline] fn rem_assign_element_wise(&mut self, rhs: $VectorN<S>) { $(self.$field %= rhs.$field);+ }
         }
 
+        impl<S: BaseNum> CheckedElementWise for $VectorN<S> {
+            #[inline] fn checked_div_element_wise(self, rhs: $VectorN<S>) -> Option<$VectorN<S>> {
+                if $(rhs.$field.is_zero())||+ { None } else { Some(self.div_element_wise(rhs)) }
+            }
+            #[inline] fn checked_rem_element_wise(self, rhs: $VectorN<S>) -> Option<$VectorN<S>> {
+                if $(rhs.$field.is_zero())||+ { None } else { Some(self.rem_element_wise(rhs)) }
+            }
+        }
+
         impl<S: BaseNum> ElementWise<S> for $VectorN<S> {
             default_fn!( add_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field + rhs),+) } );
             default_fn!( sub_element_wise(self, rhs: S) -> $VectorN<S> { $VectorN::new($(self.$field - rhs),+) } );
@@ -335,6 +549,15 @@ macro_rules! impl_vector {
             #[inline] fn rem_assign_element_wise(&mut self, rhs: S) { $(self.$field %= rhs);+ }
         }
 
+        impl<S: BaseNum> CheckedElementWise<S> for $VectorN<S> {
+            #[inline] fn checked_div_element_wise(self, rhs: S) -> Option<$VectorN<S>> {
+                if rhs.is_zero() { None } else { Some(self.div_element_wise(rhs)) }
+            }
+            #[inline] fn checked_rem_element_wise(self, rhs: S) -> Option<$VectorN<S>> {
+                if rhs.is_zero() { None } else { Some(self.rem_element_wise(rhs)) }
+            }
+        }
+
         impl_scalar_ops!($VectorN<usize> { $($field),+ });
         impl_scalar_ops!($VectorN<u8> { $($field),+ });
         impl_scalar_ops!($VectorN<u16> { $($field),+ });
@@ -353,6 +576,7 @@ macro_rules! impl_vector {
         impl_index_operators!($VectorN<S>, $n, [S], RangeTo<usize>);
         impl_index_operators!($VectorN<S>, $n, [S], RangeFrom<usize>);
         impl_index_operators!($VectorN<S>, $n, [S], RangeFull);
+        impl_lexicographic_ord!($VectorN<S>, $n);
     }
 }
 
@@ -375,6 +599,11 @@ impl_vector!(Vector2 { x, y }, 2, vec2);
 impl_vector!(Vector3 { x, y, z }, 3, vec3);
 impl_vector!(Vector4 { x, y, z, w }, 4, vec4);
 
+impl_axis_index_operators!(Vector1<S> { X: x }, Axis1);
+impl_axis_index_operators!(Vector2<S> { X: x, Y: y }, Axis2);
+impl_axis_index_operators!(Vector3<S> { X: x, Y: y, Z: z }, Axis3);
+impl_axis_index_operators!(Vector4<S> { X: x, Y: y, Z: z, W: w }, Axis4);
+
 impl_fixed_array_conversions!(Vector1<S> { x: 0 }, 1);
 impl_fixed_array_conversions!(Vector2<S> { x: 0, y: 1 }, 2);
 impl_fixed_array_conversions!(Vector3<S> { x: 0, y: 1, z: 2 }, 3);
@@ -414,6 +643,52 @@ impl<S: BaseNum> Vector2<S> {
         (self.x * other.y) - (self.y * other.x)
     }
 
+    /// The [wedge](https://en.wikipedia.org/wiki/Exterior_algebra) (or
+    /// outer) product of the vector and `other`.
+    ///
+    /// In two dimensions the resulting bivector has a single `xy` component,
+    /// the signed area of the parallelogram spanned by the two vectors. This
+    /// is the same value as `perp_dot`.
+    #[inline]
+    pub fn wedge(self, other: Vector2<S>) -> S {
+        self.perp_dot(other)
+    }
+
+    /// Rotate the vector counter-clockwise by `angle`.
+    ///
+    /// This is equivalent to, but cheaper than, rotating it with a `Basis2`.
+    #[inline]
+    pub fn rotate<A: Into<Rad<S>>>(self, angle: A) -> Vector2<S>
+    where
+        S: BaseFloat,
+    {
+        let (s, c) = Rad::sin_cos(angle.into());
+        Vector2::new(self.x * c - self.y * s, self.x * s + self.y * c)
+    }
+
+    /// The unit vector at `angle` counter-clockwise from the `x` axis.
+    #[inline]
+    pub fn from_angle<A: Into<Rad<S>>>(angle: A) -> Vector2<S>
+    where
+        S: BaseFloat,
+    {
+        let (s, c) = Rad::sin_cos(angle.into());
+        Vector2::new(c, s)
+    }
+
+    /// The counter-clockwise angle from the `x` axis to the vector, in the
+    /// range `(-π, π]`.
+    ///
+    /// This is the inverse of `from_angle` for unit vectors, and ignores the
+    /// magnitude otherwise.
+    #[inline]
+    pub fn to_angle(self) -> Rad<S>
+    where
+        S: BaseFloat,
+    {
+        Rad::atan2(self.y, self.x)
+    }
+
     /// Create a `Vector3`, using the `x` and `y` values from this vector, and the
     /// provided `z`.
     #[inline]
@@ -453,6 +728,137 @@ impl<S: BaseNum> Vector3<S> {
         )
     }
 
+    /// The [wedge](https://en.wikipedia.org/wiki/Exterior_algebra) (or
+    /// outer) product of the vector and `other`.
+    ///
+    /// The resulting bivector represents the oriented plane spanned by the
+    /// two vectors, and is returned as its `yz`, `zx` and `xy` components. In
+    /// three dimensions these coincide with the components of the cross
+    /// product, which is the bivector's dual.
+    #[inline]
+    pub fn wedge(self, other: Vector3<S>) -> Vector3<S> {
+        self.cross(other)
+    }
+
+    /// The scalar triple product `self · (b × c)`.
+    ///
+    /// This is the signed volume of the parallelepiped spanned by the three
+    /// vectors, and equal to the determinant of the matrix with them as
+    /// columns.
+    #[inline]
+    pub fn scalar_triple(self, b: Vector3<S>, c: Vector3<S>) -> S {
+        self.dot(b.cross(c))
+    }
+
+    /// The vector triple product `self × (b × c)`.
+    #[inline]
+    pub fn vector_triple(self, b: Vector3<S>, c: Vector3<S>) -> Vector3<S> {
+        self.cross(b.cross(c))
+    }
+
+    /// Project the vector onto the plane through the origin with the given
+    /// `normal`, removing its component along the normal.
+    ///
+    /// The normal does not have to be unit length, but it must not be zero.
+    #[inline]
+    pub fn project_to_plane(self, normal: Vector3<S>) -> Vector3<S> {
+        self - self.project_on(normal)
+    }
+
+    /// Make the vector orthogonal to `reference` and normalize it.
+    ///
+    /// This is a single Gram-Schmidt step, e.g. for re-orthogonalizing a
+    /// tangent against a surface normal. The reference does not have to be
+    /// unit length, but it must not be zero. If the vector is zero or
+    /// parallel to the reference there is no unique answer, so an arbitrary
+    /// unit vector orthogonal to the reference is returned.
+    pub fn orthonormalize_against(self, reference: Vector3<S>) -> Vector3<S>
+    where
+        S: BaseFloat,
+    {
+        let mut v = self.project_to_plane(reference);
+        // compare against the original length, so that rounding noise left
+        // over from a nearly parallel vector is not normalized
+        if v.magnitude2() <= self.magnitude2() * S::epsilon() {
+            v = Vector3::unit_x().cross(reference);
+            if ulps_eq!(v, &Vector3::zero()) {
+                v = Vector3::unit_y().cross(reference);
+            }
+        }
+        v.normalize()
+    }
+
+    /// Normalized linear interpolation between two unit direction vectors.
+    ///
+    /// This is cheaper than `slerp` and follows the same path, but does not
+    /// move at a constant angular speed. The difference is small when the
+    /// directions are close together.
+    ///
+    /// If the interpolated vector is zero, which happens halfway between
+    /// opposite directions, this falls back to `slerp` instead of returning
+    /// NaN.
+    pub fn nlerp(self, other: Vector3<S>, amount: S) -> Vector3<S>
+    where
+        S: BaseFloat,
+    {
+        let v = self + (other - self) * amount;
+        if ulps_eq!(v, &Vector3::zero()) {
+            self.slerp(other, amount)
+        } else {
+            v.normalize()
+        }
+    }
+
+    /// Spherical linear interpolation between two unit direction vectors.
+    ///
+    /// This interpolates along the great circle through `self` and `other`,
+    /// rather than along the chord between them, so the angular speed is
+    /// constant. Both vectors should be normalized first.
+    ///
+    /// Nearly parallel vectors fall back to a normalized linear
+    /// interpolation. For anti-parallel vectors there is no unique great
+    /// circle, so an arbitrary one orthogonal to `self` is chosen.
+    pub fn slerp(self, other: Vector3<S>, amount: S) -> Vector3<S>
+    where
+        S: BaseFloat,
+    {
+        let dot = self.dot(other);
+        let dot_threshold: S = NumCast::from(0.9995f64).unwrap();
+
+        // if the vectors are close together use normalized `lerp`
+        if dot > dot_threshold {
+            return (self + (other - self) * amount).normalize();
+        }
+
+        let mut ortho = other - self * dot;
+        if ulps_eq!(ortho, &Vector3::zero()) {
+            ortho = Vector3::unit_x().cross(self);
+            if ulps_eq!(ortho, &Vector3::zero()) {
+                ortho = Vector3::unit_y().cross(self);
+            }
+        }
+        let ortho = ortho.normalize();
+
+        // stay within the domain of acos()
+        let theta = Rad::acos(dot.min(S::one()).max(-S::one())) * amount;
+        self * Rad::cos(theta) + ortho * Rad::sin(theta)
+    }
+
+    /// Rotate the vector by `angle` around `axis`, using Rodrigues' rotation
+    /// formula. The axis must be normalized.
+    ///
+    /// This gives the same result as rotating with
+    /// `Quaternion::from_axis_angle`, without building the quaternion or a
+    /// rotation matrix first.
+    #[inline]
+    pub fn rotate_axis_angle<A: Into<Rad<S>>>(self, axis: Vector3<S>, angle: A) -> Vector3<S>
+    where
+        S: BaseFloat,
+    {
+        let (s, c) = Rad::sin_cos(angle.into());
+        self * c + axis.cross(self) * s + axis * (axis.dot(self) * (S::one() - c))
+    }
+
     /// Create a `Vector4`, using the `x`, `y` and `z` values from this vector, and the
     /// provided `w`.
     #[inline]
@@ -494,6 +900,22 @@ impl<S: BaseNum> Vector4<S> {
         Vector4::new(S::zero(), S::zero(), S::zero(), S::one())
     }
 
+    /// Create the homogeneous coordinates of a position, with a `w` of one,
+    /// so that it is affected by the translation of a matrix.
+    ///
+    /// This is the same as `Point3::from_vec(v).to_homogeneous()`.
+    #[inline]
+    pub fn from_point(v: Vector3<S>) -> Vector4<S> {
+        v.extend(S::one())
+    }
+
+    /// Create the homogeneous coordinates of a direction, with a `w` of zero,
+    /// so that it is not affected by the translation of a matrix.
+    #[inline]
+    pub fn from_direction(v: Vector3<S>) -> Vector4<S> {
+        v.extend(S::zero())
+    }
+
     /// Create a `Vector3`, dropping the `w` value.
     #[inline]
     pub fn truncate(self) -> Vector3<S> {
@@ -512,6 +934,21 @@ impl<S: BaseNum> Vector4<S> {
         }
     }
 
+    /// Perform the perspective divide, dividing `x`, `y` and `z` by `w`.
+    ///
+    /// This takes a homogeneous coordinate, such as a clip space position, to
+    /// its Cartesian equivalent, such as a position in normalized device
+    /// coordinates. Returns `None` if `w` is zero, as the vector then
+    /// represents a point at infinity.
+    #[inline]
+    pub fn homogenize(self) -> Option<Vector3<S>> {
+        if self.w.is_zero() {
+            None
+        } else {
+            Some(self.truncate() / self.w)
+        }
+    }
+
     impl_swizzle_functions!(Vector1, Vector2, Vector3, Vector4, S, xyzw);
 }
 
@@ -596,6 +1033,43 @@ impl<S: fmt::Debug> fmt::Debug for Vector4<S> {
     }
 }
 
+/// Write the elements as a bracketed list, formatting each of them with the
+/// flags of `f`, so that e.g. the precision is respected.
+fn fmt_vector<S: fmt::Display>(f: &mut fmt::Formatter, elements: &[S]) -> fmt::Result {
+    write!(f, "[")?;
+    for (i, e) in elements.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        fmt::Display::fmt(e, f)?;
+    }
+    write!(f, "]")
+}
+
+impl<S: fmt::Display> fmt::Display for Vector1<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(f, AsRef::<[S; 1]>::as_ref(self))
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Vector2<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(f, AsRef::<[S; 2]>::as_ref(self))
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Vector3<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(f, AsRef::<[S; 3]>::as_ref(self))
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Vector4<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_vector(f, AsRef::<[S; 4]>::as_ref(self))
+    }
+}
+
 #[cfg(feature = "bytemuck")]
 impl_bytemuck_cast!(Vector1);
 
@@ -608,6 +1082,15 @@ impl_bytemuck_cast!(Vector3);
 #[cfg(feature = "bytemuck")]
 impl_bytemuck_cast!(Vector4);
 
+#[cfg(feature = "float-hash")]
+impl_float_hash!(Vector1 { x });
+#[cfg(feature = "float-hash")]
+impl_float_hash!(Vector2 { x, y });
+#[cfg(feature = "float-hash")]
+impl_float_hash!(Vector3 { x, y, z });
+#[cfg(feature = "float-hash")]
+impl_float_hash!(Vector4 { x, y, z, w });
+
 #[cfg(feature = "mint")]
 impl_mint_conversions!(Vector2 { x, y }, Vector2);
 #[cfg(feature = "mint")]
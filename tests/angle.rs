@@ -16,8 +16,10 @@
 #[macro_use]
 extern crate approx;
 extern crate cgmath;
+#[cfg(feature = "rand")]
+extern crate rand;
 
-use cgmath::{Angle, Deg, Rad};
+use cgmath::{Angle, AngleExt, Deg, Rad};
 
 #[test]
 fn test_normalize() {
@@ -99,3 +101,52 @@ mod deg {
         );
     }
 }
+
+#[test]
+fn test_angle_ext() {
+    assert_eq!(90.0f64.deg(), Deg(90.0));
+    assert_ulps_eq!(Rad::from(90.0f64.deg()), Rad::turn_div_4());
+    assert_eq!(1.5f32.rad(), Rad(1.5));
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Deg(90.0)), "90°");
+    assert_eq!(format!("{:.2}", Deg(12.3456)), "12.35°");
+    assert_eq!(
+        format!("{:.4}", Rad(std::f64::consts::FRAC_PI_2)),
+        "1.5708 rad"
+    );
+    assert_eq!(format!("{}", Rad(-0.5f32)), "-0.5 rad");
+
+    // `Debug` is unchanged
+    assert_eq!(format!("{:?}", Deg(90.0)), "90.0°");
+    assert_eq!(format!("{:?}", Rad(1.5)), "1.5 rad");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_random_sampling() {
+    use cgmath::UniformAngle;
+    use rand::{rngs::SmallRng, Rng, SeedableRng};
+
+    let mut rng = SmallRng::seed_from_u64(0x5eed);
+    for _ in 0..1000 {
+        let r: Rad<f64> = rng.random();
+        assert!(r >= Rad(0.0) && r < Rad::full_turn());
+        let d: Deg<f32> = rng.random();
+        assert!(d >= Deg(0.0) && d < Deg(360.0));
+
+        let r = rng.sample(UniformAngle::new(Rad(-0.5f64), Rad(0.25)));
+        assert!(r >= Rad(-0.5) && r < Rad(0.25));
+        let d = rng.sample(UniformAngle::new(Deg(90.0f32), Deg(135.0)));
+        assert!(d >= Deg(90.0) && d < Deg(135.0));
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+#[should_panic]
+fn test_uniform_angle_empty_range() {
+    cgmath::UniformAngle::new(Deg(10.0f64), Deg(10.0));
+}
@@ -0,0 +1,108 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn assert_contains_all(sphere: Sphere3<f64>, points: &[Point3<f64>]) {
+    for &p in points {
+        assert!(
+            sphere.center.distance(p) <= sphere.radius * (1.0 + 1e-12),
+            "{:?} is not inside {:?}",
+            p,
+            sphere
+        );
+    }
+}
+
+#[test]
+fn test_from_points() {
+    assert_eq!(Sphere3::<f64>::from_points(&[]), None);
+
+    let p = Point3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(Sphere3::from_points(&[p]), Some(Sphere3::new(p, 0.0)));
+
+    let points = [
+        Point3::new(-1.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(0.5, 0.5, 0.5),
+    ];
+    let sphere = Sphere3::from_points(&points).unwrap();
+    assert_contains_all(sphere, &points);
+    assert_ulps_eq!(sphere.center, Point3::new(0.0, 0.0, 0.0));
+    assert_ulps_eq!(sphere.radius, 1.0);
+
+    // a simple linear congruential generator keeps the sample points deterministic
+    let mut seed = 12345u64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0
+    };
+    let points: Vec<_> = (0..200)
+        .map(|_| Point3::new(next(), next() * 0.5, next() + 3.0))
+        .collect();
+    let sphere = Sphere3::from_points(&points).unwrap();
+    assert_contains_all(sphere, &points);
+    for &p in &points {
+        assert!(sphere.contains(sphere.center + (p - sphere.center) * 0.999));
+    }
+}
+
+#[test]
+fn test_contains() {
+    let sphere = Sphere3::new(Point3::new(1.0f64, 1.0, 1.0), 2.0);
+    assert!(sphere.contains(Point3::new(1.0, 1.0, 1.0)));
+    assert!(sphere.contains(Point3::new(3.0, 1.0, 1.0)));
+    assert!(!sphere.contains(Point3::new(3.0, 1.0, 1.1)));
+}
+
+#[test]
+fn test_intersects() {
+    let a = Sphere3::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    let b = Sphere3::new(Point3::new(1.5f64, 0.0, 0.0), 1.0);
+    let c = Sphere3::new(Point3::new(0.0f64, 3.0, 0.0), 1.0);
+    let d = Sphere3::new(Point3::new(0.0f64, 2.0, 0.0), 1.0);
+    assert!(a.intersects(&b));
+    assert!(b.intersects(&a));
+    assert!(!a.intersects(&c));
+    assert!(!c.intersects(&a));
+    // touching
+    assert!(a.intersects(&d));
+    assert!(a.intersects(&a));
+}
+
+#[test]
+fn test_union() {
+    let a = Sphere3::new(Point3::new(0.0f64, 0.0, 0.0), 1.0);
+    let b = Sphere3::new(Point3::new(4.0f64, 0.0, 0.0), 1.0);
+    let u = a.union(&b);
+    assert_ulps_eq!(u.center, Point3::new(2.0, 0.0, 0.0));
+    assert_ulps_eq!(u.radius, 3.0);
+    assert_eq!(a.union(&b), b.union(&a));
+
+    // one inside the other
+    let big = Sphere3::new(Point3::new(0.0f64, 0.0, 0.0), 5.0);
+    let small = Sphere3::new(Point3::new(1.0f64, 1.0, 0.0), 1.0);
+    assert_eq!(big.union(&small), big);
+    assert_eq!(small.union(&big), big);
+}
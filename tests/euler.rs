@@ -0,0 +1,77 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_normalized() {
+    let e = Euler::new(Deg(370.0f64), Deg(-190.0), Deg(725.0)).normalized();
+    assert_ulps_eq!(e, Euler::new(Deg(10.0), Deg(170.0), Deg(5.0)));
+
+    let e = Euler::new(Deg(-180.0f64), Deg(180.0), Deg(540.0)).normalized();
+    assert_ulps_eq!(e, Euler::new(Deg(-180.0), Deg(-180.0), Deg(-180.0)));
+
+    let e = Euler::new(Rad(7.0f64), Rad(-0.5), Rad(0.0)).normalized();
+    assert_relative_eq!(
+        e,
+        Euler::new(Rad(7.0 - 2.0 * std::f64::consts::PI), Rad(-0.5), Rad(0.0)),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_clamp() {
+    let min = Euler::new(Deg(-10.0f64), Deg(0.0), Deg(-90.0));
+    let max = Euler::new(Deg(10.0f64), Deg(45.0), Deg(90.0));
+
+    let e = Euler::new(Deg(20.0), Deg(-5.0), Deg(30.0));
+    assert_eq!(
+        e.clamp(min, max),
+        Euler::new(Deg(10.0), Deg(0.0), Deg(30.0))
+    );
+
+    let e = Euler::new(Deg(-20.0), Deg(50.0), Deg(-120.0));
+    assert_eq!(
+        e.clamp(min, max),
+        Euler::new(Deg(-10.0), Deg(45.0), Deg(-90.0))
+    );
+}
+
+#[test]
+fn test_lerp() {
+    let a = Euler::new(Deg(350.0f64), Deg(10.0), Deg(-170.0));
+    let b = Euler::new(Deg(10.0f64), Deg(50.0), Deg(170.0));
+
+    // x and z wrap around through 0° and 180°, y does not need to
+    assert_ulps_eq!(a.lerp(b, 0.5), Euler::new(Deg(0.0), Deg(30.0), Deg(180.0)));
+    assert_ulps_eq!(
+        a.lerp(b, 0.25),
+        Euler::new(Deg(-5.0), Deg(20.0), Deg(-175.0))
+    );
+    assert_ulps_eq!(
+        a.lerp(b, 0.0),
+        Euler::new(Deg(-10.0), Deg(10.0), Deg(-170.0))
+    );
+    assert_ulps_eq!(a.lerp(b, 1.0), b);
+
+    let a = Euler::new(Rad(0.1f64), Rad(0.0), Rad(-3.0));
+    let b = Euler::new(Rad(0.3f64), Rad(0.0), Rad(3.0));
+    let e = a.lerp(b, 0.5);
+    assert_relative_eq!(e.x, Rad(0.2), epsilon = 1e-12);
+    assert_relative_eq!(e.z.0.abs(), std::f64::consts::PI, epsilon = 1e-12);
+}
@@ -0,0 +1,87 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+#[test]
+fn test_smoothstep() {
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, 0.0), 0.0);
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, 0.5), 0.5);
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, 1.0), 1.0);
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, 0.25), 0.15625);
+    assert_ulps_eq!(smoothstep(2.0f64, 4.0, 3.0), 0.5);
+}
+
+#[test]
+fn test_smoothstep_clamped() {
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, -3.0), 0.0);
+    assert_ulps_eq!(smoothstep(0.0f64, 1.0, 7.0), 1.0);
+}
+
+#[test]
+fn test_smoothstep_equal_edges() {
+    // the ratio is 0/0 at the edge, which must not leak out as NaN
+    assert_eq!(smoothstep(2.0f64, 2.0, 2.0), 0.0);
+    assert_eq!(smootherstep(2.0f64, 2.0, 2.0), 0.0);
+    assert_eq!(smoothstep(2.0f64, 2.0, 3.0), 1.0);
+    assert_eq!(smootherstep(2.0f64, 2.0, 1.0), 0.0);
+}
+
+#[test]
+fn test_smootherstep() {
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, 0.0), 0.0);
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, 0.5), 0.5);
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, 1.0), 1.0);
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, 0.25), 0.103515625);
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, -1.0), 0.0);
+    assert_ulps_eq!(smootherstep(0.0f64, 1.0, 2.0), 1.0);
+}
+
+#[test]
+fn test_smoothstep_vector() {
+    let edge0 = Vector3::new(0.0f64, 0.0, 2.0);
+    let edge1 = Vector3::new(1.0f64, 1.0, 4.0);
+    assert_ulps_eq!(
+        smoothstep(edge0, edge1, Vector3::new(-1.0, 0.5, 3.0)),
+        Vector3::new(0.0, 0.5, 0.5)
+    );
+    assert_ulps_eq!(
+        smootherstep(edge0, edge1, Vector3::new(0.25, 2.0, 1.0)),
+        Vector3::new(0.103515625, 1.0, 0.0)
+    );
+}
+
+#[test]
+fn test_clamp() {
+    assert_eq!(clamp(5, 0, 3), 3);
+    assert_eq!(clamp(-5, 0, 3), 0);
+    assert_eq!(clamp(2, 0, 3), 2);
+    assert_eq!(clamp(0.25f64, -1.0, 1.0), 0.25);
+    assert!(clamp(f64::NAN, 0.0, 1.0).is_nan());
+}
+
+#[test]
+fn test_saturate() {
+    assert_eq!(saturate(-0.5f64), 0.0);
+    assert_eq!(saturate(1.5f64), 1.0);
+    assert_eq!(saturate(0.3f32), 0.3);
+    assert_eq!(
+        Vector3::new(-0.5f64, 0.3, 1.5).saturate(),
+        Vector3::new(0.0, 0.3, 1.0)
+    );
+}
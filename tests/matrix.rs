@@ -446,6 +446,183 @@ pub mod matrix3 {
         assert_eq!(res, Vector3::new(1., 2., 1.));
     }
 
+    #[test]
+    fn test_display() {
+        let m = Matrix3::from_row_major([[1.0f64, -2.0, 3.3], [10.0, 0.0, -0.5], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            format!("{:.1}", m),
+            "[ 1.0, -2.0,  3.3]\n[10.0,  0.0, -0.5]\n[ 7.0,  8.0,  9.0]"
+        );
+        assert_eq!(
+            format!("{}", Matrix3::<i32>::new(1, 0, 0, 0, 1, 0, 0, 0, 1)),
+            "[1, 0, 0]\n[0, 1, 0]\n[0, 0, 1]"
+        );
+        assert_eq!(
+            format!("{}", Matrix2::new(1, 2, 3, -40)),
+            "[  1,   3]\n[  2, -40]"
+        );
+    }
+
+    #[test]
+    fn test_transform_2d() {
+        let translation = Matrix3::from_translation(Vector2::new(3.0f64, -1.0));
+        let p = Point2::new(2.0f64, 5.0);
+        assert_eq!(translation.transform_point(p), Point2::new(5.0, 4.0));
+        let v = Vector2::new(2.0f64, 5.0);
+        // `Matrix3` is also a 3D transform, so vectors need disambiguating
+        assert_eq!(
+            Transform::<Point2<f64>>::transform_vector(&translation, v),
+            v
+        );
+
+        let scale = Matrix3::from_nonuniform_scale(2.0f64, 0.5);
+        assert_eq!(scale.transform_point(p), Point2::new(4.0, 2.5));
+        assert_eq!(
+            Matrix3::from_scale(3.0f64).transform_point(p),
+            Point2::new(6.0, 15.0)
+        );
+
+        // scale first, then translate
+        let m = translation * scale;
+        assert_eq!(m.transform_point(p), Point2::new(7.0, 1.5));
+        assert_eq!(
+            m.invert().unwrap().transform_point(Point2::new(7.0, 1.5)),
+            p
+        );
+    }
+
+    #[test]
+    fn test_determinant_sign() {
+        let reflection = Matrix3::from_nonuniform_scale(-1.0f64, 1.0);
+        assert_eq!(reflection.determinant_sign(), -1);
+        assert_eq!(Matrix3::from_angle_z(Deg(30.0f64)).determinant_sign(), 1);
+        assert_eq!(A.determinant_sign(), 0);
+
+        assert!(reflection.is_invertible_with_epsilon(1e-6));
+        assert!(!A.is_invertible_with_epsilon(1e-6));
+        assert!(!Matrix3::from_value(1e-3f64).is_invertible_with_epsilon(1e-6));
+    }
+
+    #[test]
+    fn test_invert_transpose() {
+        let mat = Matrix3::new(2.0f64, 0.0, 1.0, 1.0, 3.0, 0.0, 0.0, 1.0, 4.0);
+        assert_ulps_eq!(
+            mat.invert_transpose().unwrap(),
+            mat.invert().unwrap().transpose()
+        );
+        assert!(Matrix3::<f64>::zero().invert_transpose().is_none());
+    }
+
+    #[test]
+    fn test_polar_decomposition() {
+        // a pure rotation is its own orthogonal factor
+        let rot = Matrix3::from_axis_angle(Vector3::new(1.0f64, -2.0, 0.5).normalize(), Deg(40.0));
+        let (r, s) = rot.polar_decomposition();
+        assert_ulps_eq!(r, rot);
+        assert_ulps_eq!(s, Matrix3::identity());
+
+        // a scaled and sheared rotation
+        let m = rot * Matrix3::from_shear(0.4, -0.2) * Matrix3::from_nonuniform_scale(2.0, 0.5);
+        let (r, s) = m.polar_decomposition();
+        assert_relative_eq!(r * s, m, epsilon = 1e-12);
+        assert_relative_eq!(r.transpose() * r, Matrix3::identity(), epsilon = 1e-12);
+        assert_relative_eq!(r.determinant(), 1.0, epsilon = 1e-12);
+        assert_eq!(s, s.transpose());
+
+        // `S` is positive definite
+        assert!(s.x.x > 0.0 && s.determinant() > 0.0);
+    }
+
+    #[test]
+    fn test_from_row_major() {
+        let rows = [[1.0f64, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        let m = Matrix3::from_row_major(rows);
+        assert_eq!(m, Matrix3::from(rows).transpose());
+        assert_eq!(m.row(0), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(m.x, Vector3::new(1.0, 4.0, 7.0));
+    }
+
+    #[test]
+    fn test_max_abs() {
+        let m = Matrix3::new(1.0f64, -7.5, 2.0, 3.0, 0.0, -1.0, 6.0, -2.0, 4.0);
+        assert_eq!(m.max_abs(), 7.5);
+        assert_eq!((-m).max_abs(), 7.5);
+        assert_eq!(Matrix3::<f64>::zero().max_abs(), 0.0);
+        assert_eq!(Matrix2::new(-3.0f32, 1.0, 2.0, f32::NAN).max_abs(), 3.0);
+        assert_eq!(Matrix4::from_scale(-2.0f64).max_abs(), 2.0);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let identity = Matrix3::from_fn(|r, c| if r == c { 1.0f64 } else { 0.0 });
+        assert_eq!(identity, Matrix3::identity());
+
+        let m = Matrix3::from_fn(|r, c| (3 * r + c) as f64 + 1.0);
+        assert_eq!(
+            m,
+            Matrix3::from_row_major([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+        );
+
+        // elements are produced in column-major order
+        let mut count = 0;
+        let order = Matrix3::from_fn(|_, _| {
+            count += 1;
+            count
+        });
+        assert_eq!(order, Matrix3::new(1, 2, 3, 4, 5, 6, 7, 8, 9));
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        let m = Matrix3::from_diagonal(Vector3::new(2.0f64, 3.0, 4.0));
+        #[cfg_attr(rustfmt, rustfmt_skip)]
+        let expected = Matrix3::new(
+            2.0, 0.0, 0.0,
+            0.0, 3.0, 0.0,
+            0.0, 0.0, 4.0,
+        );
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix3::new(2.0f64, 1.0, 0.0, -1.0, 3.0, 4.0, 0.5, 0.0, 1.0);
+        let det = m.determinant();
+        assert_ulps_eq!(m.adjugate() * m, Matrix3::from_value(det));
+        assert_ulps_eq!(m * m.adjugate(), Matrix3::from_value(det));
+        assert_ulps_eq!(m.adjugate() / det, m.invert().unwrap());
+
+        // singular matrices have an adjugate too
+        let m = Matrix3::new(1.0f64, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 1.0);
+        assert_eq!(m.determinant(), 0.0);
+        assert_ulps_eq!(m.adjugate() * m, Matrix3::zero());
+        assert!(m.adjugate() != Matrix3::zero());
+    }
+
+    #[test]
+    fn test_pow() {
+        let m = Matrix3::new(1.0f64, 2.0, 0.0, -1.0, 0.5, 3.0, 2.0, 0.0, 1.0);
+        assert_eq!(m.pow(0), Matrix3::identity());
+        assert_eq!(m.pow(1), m);
+        assert_eq!(m.pow(3), m * m * m);
+        assert_eq!(m.pow(6), m * m * m * m * m * m);
+    }
+
+    #[test]
+    fn test_from_shear() {
+        let m = Matrix3::from_shear(1.0f64, 0.0);
+        assert_eq!(
+            m.transform_point(Point2::new(2.0, 3.0)),
+            Point2::new(5.0, 3.0)
+        );
+
+        let m = Matrix3::from_shear(0.0f64, 2.0);
+        assert_eq!(
+            m.transform_point(Point2::new(2.0, 3.0)),
+            Point2::new(2.0, 7.0)
+        );
+    }
+
     mod from_axis_x {
         use cgmath::*;
 
@@ -665,6 +842,54 @@ pub mod matrix3 {
         }
     }
 
+    mod from_euler_order {
+        use cgmath::*;
+
+        fn check(order: EulerOrder, axes: [Vector3<f64>; 3]) {
+            let (x, y, z) = (Rad(0.3f64), Rad(-1.1f64), Rad(2.2f64));
+            let angle = |axis: Vector3<f64>| {
+                if axis == Vector3::unit_x() {
+                    x
+                } else if axis == Vector3::unit_y() {
+                    y
+                } else {
+                    z
+                }
+            };
+
+            let vec = vec3(1.0f64, 2.0, 3.0);
+            // intrinsic rotations, so the last one is applied to the vector first
+            let expected = axes.iter().rev().fold(vec, |v, &axis| {
+                Matrix3::from_axis_angle(axis, angle(axis)) * v
+            });
+
+            let rot = Matrix3::from_euler_order(x, y, z, order);
+            assert_relative_eq!(rot * vec, expected, epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_orders() {
+            let (x, y, z) = (Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z());
+            check(EulerOrder::Xyz, [x, y, z]);
+            check(EulerOrder::Xzy, [x, z, y]);
+            check(EulerOrder::Yxz, [y, x, z]);
+            check(EulerOrder::Yzx, [y, z, x]);
+            check(EulerOrder::Zxy, [z, x, y]);
+            check(EulerOrder::Zyx, [z, y, x]);
+        }
+
+        #[test]
+        fn test_xyz_matches_from_euler() {
+            let euler = Euler::new(Rad(0.3f64), Rad(-1.1), Rad(2.2));
+            assert_relative_eq!(
+                Matrix3::from_euler_order(euler.x, euler.y, euler.z, EulerOrder::Xyz),
+                Matrix3::from_euler(euler),
+                epsilon = 1e-12
+            );
+            assert_eq!(Matrix3::from_euler(euler), Matrix3::from(euler));
+        }
+    }
+
     mod rotate_from_axis_angle {
         use cgmath::*;
 
@@ -1018,6 +1243,23 @@ pub mod matrix4 {
         assert_eq!(mut_a, A.transpose());
     }
 
+    #[test]
+    fn test_transpose_self_off_diagonal() {
+        let m = Matrix4::from_array([
+            1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0,
+        ]);
+        let mut t = m;
+        t.transpose_self();
+        for c in 0..4 {
+            for r in 0..4 {
+                assert_eq!(t[c][r], m[r][c]);
+            }
+        }
+        t.transpose_self();
+        assert_eq!(t, m);
+    }
+
     #[test]
     fn test_invert() {
         assert!(Matrix4::<f64>::identity().invert().unwrap().is_identity());
@@ -1131,6 +1373,27 @@ pub mod matrix4 {
         assert!(Matrix4::from_value(6.0f64).is_diagonal());
     }
 
+    #[test]
+    fn test_is_identity_with_epsilon() {
+        let identity = Matrix4::<f64>::identity();
+        assert!(identity.is_identity_with_epsilon(0.0));
+        assert!(identity.is_finite());
+
+        let mut perturbed = identity;
+        perturbed[3][1] = 1e-5;
+        assert!(!perturbed.is_identity());
+        assert!(perturbed.is_identity_with_epsilon(1e-4));
+        assert!(!perturbed.is_identity_with_epsilon(1e-6));
+
+        let mut nan = identity;
+        nan[2][2] = f64::NAN;
+        assert!(!nan.is_finite());
+        assert!(!nan.is_identity_with_epsilon(1.0));
+
+        assert!(Matrix2::new(1.0f32, 1e-3, 0.0, 1.0).is_identity_with_epsilon(1e-2));
+        assert!(!Matrix3::from_scale(1.1f32).is_identity_with_epsilon(1e-2));
+    }
+
     #[test]
     fn test_from_translation() {
         let mat = Matrix4::from_translation(Vector3::new(1.0f64, 2.0f64, 3.0f64));
@@ -1139,6 +1402,399 @@ pub mod matrix4 {
         assert_eq!(res, Vector4::new(1., 2., 3., 1.));
     }
 
+    #[test]
+    fn test_determinant_sign() {
+        let reflection = Matrix4::from_nonuniform_scale(1.0f64, 1.0, -2.0);
+        assert_eq!(reflection.determinant_sign(), -1);
+        assert_eq!(Matrix4::from_scale(2.0f64).determinant_sign(), 1);
+        assert_eq!(
+            Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0).determinant_sign(),
+            0
+        );
+
+        assert!(reflection.is_invertible_with_epsilon(1e-6));
+        assert!(!Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0).is_invertible_with_epsilon(1e-6));
+    }
+
+    #[test]
+    fn test_from_axis_angle() {
+        let axis = Vector3::new(1.0f64, -2.0, 0.5).normalize();
+        let angle = Rad(0.7f64);
+        assert_ulps_eq!(
+            Matrix4::from_axis_angle(axis, angle),
+            Matrix4::from(Matrix3::from_axis_angle(axis, angle))
+        );
+        assert_ulps_eq!(
+            Matrix4::from_angle_x(angle),
+            Matrix4::from(Matrix3::from_angle_x(angle))
+        );
+        assert_ulps_eq!(
+            Matrix4::from_angle_y(angle),
+            Matrix4::from(Matrix3::from_angle_y(angle))
+        );
+        assert_ulps_eq!(
+            Matrix4::from_angle_z(angle),
+            Matrix4::from(Matrix3::from_angle_z(angle))
+        );
+    }
+
+    #[test]
+    fn test_billboard() {
+        let position = Point3::new(1.0f64, 2.0, 3.0);
+        let camera = Point3::new(-4.0f64, 6.0, 0.5);
+        let up = Vector3::unit_y();
+
+        let mat = Matrix4::billboard(position, camera, up);
+        assert_relative_eq!(
+            mat.transform_vector(Vector3::unit_z()),
+            (camera - position).normalize(),
+            epsilon = 1e-12
+        );
+        assert_eq!(mat.transform_point(Point3::origin()), position);
+        assert_relative_eq!(mat.determinant(), 1.0, epsilon = 1e-12);
+
+        assert_eq!(
+            Matrix4::billboard(position, position, up),
+            Matrix4::from_translation(position.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_billboard_camera_above() {
+        let position = Point3::new(1.0f64, 2.0, 3.0);
+        let camera = Point3::new(1.0f64, 7.0, 3.0);
+
+        let mat = Matrix4::billboard(position, camera, Vector3::unit_y());
+        assert!(mat.is_finite());
+        assert_relative_eq!(mat.transform_vector(Vector3::unit_z()), Vector3::unit_y());
+        assert_eq!(mat.transform_point(Point3::origin()), position);
+        assert_relative_eq!(mat.determinant(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_billboard_cylindrical() {
+        let position = Point3::new(1.0f64, 2.0, 3.0);
+        let camera = Point3::new(-4.0f64, 6.0, 3.0);
+        let up = Vector3::unit_y();
+
+        let mat = Matrix4::billboard_cylindrical(position, camera, up);
+        assert_relative_eq!(
+            mat.transform_vector(Vector3::unit_z()),
+            -Vector3::unit_x(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(mat.transform_vector(Vector3::unit_y()), up, epsilon = 1e-12);
+        assert_relative_eq!(mat.determinant(), 1.0, epsilon = 1e-12);
+
+        assert_eq!(
+            Matrix4::billboard_cylindrical(position, Point3::new(1.0, 9.0, 3.0), up),
+            Matrix4::from_translation(position.to_vec())
+        );
+    }
+
+    #[test]
+    fn test_transform_point_vector() {
+        let mat = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0));
+        assert_eq!(
+            mat.transform_point(Point3::new(1.0, 1.0, 1.0)),
+            Point3::new(2.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            mat.transform_vector(Vector3::new(1.0, 1.0, 1.0)),
+            Vector3::new(1.0, 1.0, 1.0)
+        );
+
+        let proj: Matrix4<f64> = frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0);
+        assert_relative_eq!(
+            proj.transform_point(Point3::new(1.0, -1.0, -2.0)),
+            Point3::new(0.5, -0.5, 1.0 / 9.0),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_normal_matrix() {
+        let mat = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0))
+            * Matrix4::from_angle_z(Deg(40.0))
+            * Matrix4::from_nonuniform_scale(4.0f64, 1.0, 0.5);
+        let normal_mat = mat.normal_matrix().unwrap();
+
+        let normal = Vector3::new(1.0f64, 1.0, 0.0);
+        let tangent = Vector3::new(1.0f64, -1.0, 2.0);
+        assert_ulps_eq!(normal.dot(tangent), 0.0);
+
+        let normal = normal_mat * normal;
+        let tangent = mat.transform_vector(tangent);
+        assert_relative_eq!(normal.dot(tangent), 0.0, epsilon = 1e-12);
+
+        // the linear part alone would not keep the vectors perpendicular
+        let linear = Matrix3::from_cols(mat.x.truncate(), mat.y.truncate(), mat.z.truncate());
+        assert!((linear * Vector3::new(1.0, 1.0, 0.0)).dot(tangent).abs() > 1.0);
+
+        assert!(Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0)
+            .normal_matrix()
+            .is_none());
+    }
+
+    #[test]
+    fn test_adjugate() {
+        let m = Matrix4::new(
+            2.0f64, 1.0, 0.0, 3.0, -1.0, 3.0, 4.0, 0.0, 0.5, 0.0, 1.0, 2.0, 1.0, -2.0, 0.0, 1.0,
+        );
+        let det = m.determinant();
+        assert_ulps_eq!(m.adjugate() * m, Matrix4::from_value(det));
+        assert_ulps_eq!(m * m.adjugate(), Matrix4::from_value(det));
+        assert_ulps_eq!(m.adjugate() / det, m.invert().unwrap());
+    }
+
+    #[test]
+    fn test_invert_or() {
+        let fallback = Matrix4::from_scale(7.0f64);
+        assert_eq!(Matrix4::zero().invert_or(fallback), fallback);
+        assert_eq!(
+            Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0).invert_or(Matrix4::identity()),
+            Matrix4::identity()
+        );
+
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_angle_x(Deg(30.0))
+            * Matrix4::from_scale(2.0);
+        assert_eq!(m.invert_or(fallback), m.invert().unwrap());
+        assert_ulps_eq!(m * m.invert_or(fallback), Matrix4::identity());
+    }
+
+    #[test]
+    fn test_row() {
+        let mut m = Matrix4::from_array([
+            1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0,
+        ]);
+        assert_eq!(m.row(0), Vector4::new(m[0][0], m[1][0], m[2][0], m[3][0]));
+        assert_eq!(m.row(0), Vector4::new(1.0, 5.0, 9.0, 13.0));
+        assert_eq!(m.row(3), m.transpose().w);
+
+        let row = Vector4::new(-1.0, -2.0, -3.0, -4.0);
+        let old = m.replace_row(2, row);
+        assert_eq!(old, Vector4::new(3.0, 7.0, 11.0, 15.0));
+        assert_eq!(m.row(2), row);
+        assert_eq!(m.x, Vector4::new(1.0, 2.0, -1.0, 4.0));
+        assert_eq!(m.w, Vector4::new(13.0, 14.0, -4.0, 16.0));
+    }
+
+    #[test]
+    fn test_array() {
+        let array = [
+            1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0,
+        ];
+        let m = Matrix4::from_array(array);
+        assert_eq!(m.x, Vector4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(m.w, Vector4::new(13.0, 14.0, 15.0, 16.0));
+        assert_eq!(m[2][1], 10.0);
+        assert_eq!(m.to_array(), array);
+        assert_eq!(m.as_array(), &array);
+        assert_eq!(AsRef::<[f64; 16]>::as_ref(&m), &array);
+        assert_eq!(m.as_array().as_ptr(), m.as_ptr());
+        assert_eq!(Matrix4::from_array(m.to_array()), m);
+
+        let array_2d = m.to_array_2d();
+        assert_eq!(array_2d[0], [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(array_2d[3], [13.0, 14.0, 15.0, 16.0]);
+        assert_eq!(Matrix4::from(array_2d), m);
+    }
+
+    #[test]
+    fn test_frustum_planes() {
+        let proj = perspective(Deg(90.0f64), 1.0, 1.0, 10.0);
+        let dist = |plane: Vector4<f64>, p: Point3<f64>| plane.dot(p.to_homogeneous());
+
+        let planes = proj.frustum_planes();
+        for plane in &planes {
+            assert_ulps_eq!(plane.truncate().magnitude(), 1.0);
+        }
+        let [left, right, bottom, top, near, far] = planes;
+        let p = Point3::new(0.0, 0.0, -5.0);
+        assert_ulps_eq!(dist(left, p), 5.0 / 2.0f64.sqrt());
+        assert_ulps_eq!(dist(right, p), 5.0 / 2.0f64.sqrt());
+        assert_ulps_eq!(dist(bottom, p), 5.0 / 2.0f64.sqrt());
+        assert_ulps_eq!(dist(top, p), 5.0 / 2.0f64.sqrt());
+        assert_relative_eq!(dist(near, p), 4.0, epsilon = 1e-12);
+        assert_relative_eq!(dist(far, p), 5.0, epsilon = 1e-12);
+
+        // planes of a view-projection matrix are in world space
+        let view = Matrix4::look_at_rh(
+            Point3::new(3.0, 2.0, 1.0),
+            Point3::new(3.0, 2.0, -9.0),
+            Vector3::unit_y(),
+        );
+        let planes = (proj * view).frustum_planes();
+        let inside = Point3::new(4.0, 1.0, -6.0);
+        assert!(planes.iter().all(|&plane| dist(plane, inside) > 0.0));
+        for &outside in &[
+            Point3::new(3.0, 2.0, 0.5),
+            Point3::new(3.0, 2.0, -12.0),
+            Point3::new(-3.0, 2.0, -4.0),
+            Point3::new(3.0, 8.0, -4.0),
+        ] {
+            assert!(planes.iter().any(|&plane| dist(plane, outside) < 0.0));
+        }
+    }
+
+    #[test]
+    fn test_transform_points() {
+        let m = perspective(Deg(60.0f64), 1.5, 0.1, 100.0)
+            * Matrix4::from_translation(Vector3::new(1.0, -2.0, -10.0))
+            * Matrix4::from_angle_y(Deg(25.0));
+        let src: Vec<_> = (0..17)
+            .map(|i| {
+                let i = i as f64;
+                Point3::new(i * 0.5 - 4.0, 3.0 - i * 0.25, i * 0.75 - 6.0)
+            })
+            .collect();
+        let mut dst = vec![Point3::origin(); src.len()];
+        m.transform_points(&src, &mut dst);
+        for (p, q) in src.iter().zip(&dst) {
+            assert_eq!(m.transform_point(*p), *q);
+        }
+
+        m.transform_points(&[], &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "`src` and `dst` must have the same length")]
+    fn test_transform_points_length_mismatch() {
+        let src = [Point3::new(1.0f64, 2.0, 3.0); 3];
+        let mut dst = [Point3::origin(); 2];
+        Matrix4::identity().transform_points(&src, &mut dst);
+    }
+
+    #[test]
+    fn test_from_finite_array() {
+        let mut array = [
+            1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+            16.0,
+        ];
+        assert_eq!(
+            Matrix4::from_finite_array(array),
+            Some(Matrix4::from_array(array))
+        );
+
+        array[13] = f64::NAN;
+        assert_eq!(Matrix4::from_finite_array(array), None);
+        array[13] = f64::NEG_INFINITY;
+        assert_eq!(Matrix4::from_finite_array(array), None);
+    }
+
+    #[test]
+    fn test_pow() {
+        let m = Matrix4::from_translation(Vector3::new(1.0f64, 2.0, 3.0))
+            * Matrix4::from_nonuniform_scale(2.0, 0.5, -1.0)
+            * Matrix4::from_shear(1.0, 0.0, 0.0, 2.0, 0.0, 0.0);
+        assert_eq!(m.pow(0), Matrix4::identity());
+        assert_eq!(m.pow(1), m);
+        assert_eq!(m.pow(3), m * m * m);
+        assert_eq!(m.pow(5), m * m * m * m * m);
+
+        let r = Matrix4::from_angle_z(Deg(30.0f64));
+        assert_ulps_eq!(r.pow(12), Matrix4::identity());
+    }
+
+    #[test]
+    fn test_from_shear() {
+        let p = Point3::new(2.0f64, 3.0, 5.0);
+
+        let m = Matrix4::from_shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(m.transform_point(p), Point3::new(5.0, 3.0, 5.0));
+
+        let m = Matrix4::from_shear(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        assert_eq!(
+            m.transform_point(p),
+            Point3::new(2.0 + 3.0 + 10.0, 3.0 + 6.0 + 20.0, 5.0 + 10.0 + 18.0)
+        );
+        assert_eq!(m[1][0], 1.0);
+        assert_eq!(m[2][0], 2.0);
+        assert_eq!(m[0][1], 3.0);
+        assert_eq!(m[2][1], 4.0);
+        assert_eq!(m[0][2], 5.0);
+        assert_eq!(m[1][2], 6.0);
+    }
+
+    #[test]
+    fn test_translation_scale() {
+        let mat = Matrix4::from_translation(Vector3::new(1.0f64, -2.0, 3.0))
+            * Matrix4::from_angle_y(Deg(30.0))
+            * Matrix4::from_nonuniform_scale(2.0f64, 3.0, 0.5);
+        assert_ulps_eq!(mat.translation(), Vector3::new(1.0, -2.0, 3.0));
+        assert_ulps_eq!(mat.scale(), Vector3::new(2.0, 3.0, 0.5));
+    }
+
+    #[test]
+    fn test_from_trs() {
+        let t = Vector3::new(1.0f64, -2.0, 3.0);
+        let r = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -1.0).normalize(), Deg(75.0));
+        let s = Vector3::new(2.0, 0.5, 3.0);
+        let m = Matrix4::from_trs(t, r, s);
+
+        assert_ulps_eq!(
+            m,
+            Matrix4::from_translation(t)
+                * Matrix4::from(r)
+                * Matrix4::from_nonuniform_scale(s.x, s.y, s.z)
+        );
+
+        // decompose again using the translation, scale and normalized columns
+        assert_ulps_eq!(m.translation(), t);
+        let scale = m.scale();
+        assert_relative_eq!(scale, s, epsilon = 1e-12);
+        let rot = Matrix3::from_cols(
+            m.x.truncate() / scale.x,
+            m.y.truncate() / scale.y,
+            m.z.truncate() / scale.z,
+        );
+        assert_relative_eq!(Quaternion::from(rot), r, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let q0 = Quaternion::from_angle_x(Deg(20.0f64));
+        let q1 = Quaternion::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Deg(110.0));
+        let (m0, m1) = (Matrix4::from(q0), Matrix4::from(q1));
+        for &t in &[0.0, 0.25, 0.5, 0.9, 1.0] {
+            assert_relative_eq!(
+                m0.interpolate(&m1, t).unwrap(),
+                Matrix4::from(q0.slerp(q1, t)),
+                epsilon = 1e-12
+            );
+        }
+
+        let a = Matrix4::from_trs(
+            Vector3::new(0.0, 2.0, -4.0),
+            q0,
+            Vector3::new(1.0, 1.0, 2.0),
+        );
+        let b = Matrix4::from_trs(
+            Vector3::new(4.0, -2.0, 0.0),
+            q1,
+            Vector3::new(3.0, 1.0, 4.0),
+        );
+        let mid = a.interpolate(&b, 0.5).unwrap();
+        assert_relative_eq!(
+            mid.translation(),
+            Vector3::new(2.0, 0.0, -2.0),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(mid.scale(), Vector3::new(2.0, 1.0, 3.0), epsilon = 1e-12);
+        assert_relative_eq!(a.interpolate(&b, 0.0).unwrap(), a, epsilon = 1e-12);
+        assert_relative_eq!(a.interpolate(&b, 1.0).unwrap(), b, epsilon = 1e-12);
+
+        let singular = Matrix4::from_nonuniform_scale(1.0, 0.0, 1.0);
+        assert_eq!(a.interpolate(&singular, 0.5), None);
+        let mirror = Matrix4::from_nonuniform_scale(-1.0, 1.0, 1.0);
+        assert_eq!(mirror.interpolate(&a, 0.5), None);
+        let projective = perspective(Deg(60.0), 1.0, 0.1, 10.0);
+        assert_eq!(a.interpolate(&projective, 0.5), None);
+    }
+
     #[test]
     fn test_cast() {
         assert_ulps_eq!(
@@ -1188,6 +1844,50 @@ pub mod matrix4 {
         assert_abs_diff_eq!(expected, m, epsilon = 1.0e-4);
     }
 
+    #[test]
+    fn test_to_component_vec() {
+        let m = Matrix4::from_fn(|r, c| (10 * r + c) as f32);
+        let v = m.to_component_vec();
+        assert_eq!(v.len(), 16);
+        assert_eq!(&v[..4], &[0.0, 10.0, 20.0, 30.0]);
+        assert_eq!(v[13], 13.0);
+        assert_eq!(&v[..], AsRef::<[f32; 16]>::as_ref(&m));
+        assert_eq!(
+            Matrix2::new(1, 2, 3, 4).to_component_vec(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_look_to_rh_roll() {
+        let eye = Point3::new(10.0f64, 15.0, 20.0);
+        let dir = Vector3::new(1.0, 2.0, 3.0);
+        let up = Vector3::unit_y();
+        let view = Matrix4::look_to_rh(eye, dir, up);
+
+        assert_ulps_eq!(Matrix4::look_to_rh_roll(eye, dir, up, Rad(0.0)), view);
+
+        // after a quarter turn the camera's up axis is its former right axis
+        let rolled = Matrix4::look_to_rh_roll(eye, dir, up, Deg(90.0));
+        let right = view.row(0).truncate();
+        let old_up = view.row(1).truncate();
+        assert_ulps_eq!(rolled.row(1).truncate(), right);
+        assert_ulps_eq!(rolled.row(0).truncate(), -old_up);
+        assert_ulps_eq!(rolled.row(2), view.row(2));
+        assert_abs_diff_eq!(
+            rolled.transform_point(eye),
+            Point3::origin(),
+            epsilon = 1e-12
+        );
+
+        // rolling the camera turns the view about the camera's z axis
+        assert_relative_eq!(
+            Matrix4::look_to_rh_roll(eye, dir, up, Deg(30.0)),
+            Matrix4::from_angle_z(Deg(30.0)) * view,
+            epsilon = 1e-12
+        );
+    }
+
     #[test]
     fn test_look_to_lh() {
         let eye = Point3::new(10.0, 15.0, 20.0);
@@ -81,6 +81,92 @@ fn test_rem() {
     impl_test_rem!(Point2 { x, y }, 2.0f32, Point2::new(2.0f32, 4.0));
 }
 
+#[test]
+fn test_distance() {
+    let a = Point3::new(0.0f64, 0.0, 0.0);
+    let b = Point3::new(3.0f64, 4.0, 0.0);
+    assert_ulps_eq!(a.distance(b), 5.0);
+    assert_ulps_eq!(a.distance_squared(b), 25.0);
+    assert_eq!(Point2::new(1, 2).distance_squared(Point2::new(4, -2)), 25);
+}
+
+#[test]
+fn test_distance_to_line_segment() {
+    let a = Point3::new(0.0f64, 0.0, 0.0);
+    let b = Point3::new(4.0f64, 0.0, 0.0);
+
+    // perpendicular to the middle of the segment
+    let p = Point3::new(2.0f64, 3.0, 4.0);
+    assert_ulps_eq!(p.distance_to_line(a, b), 5.0);
+    assert_ulps_eq!(p.distance_to_segment(a, b), 5.0);
+
+    // beyond the `b` endpoint
+    let p = Point3::new(7.0f64, 0.0, 4.0);
+    assert_ulps_eq!(p.distance_to_line(a, b), 4.0);
+    assert_ulps_eq!(p.distance_to_segment(a, b), 5.0);
+
+    // degenerate segment
+    assert_ulps_eq!(p.distance_to_segment(a, a), p.distance(a));
+}
+
+#[test]
+fn test_array_tuple_round_trip() {
+    let p = Point3::new(1.5f32, -2.0, 3.25);
+    assert_eq!(Point3::from(<[f32; 3]>::from(p)), p);
+    assert_eq!(Point3::from(<(f32, f32, f32)>::from(p)), p);
+    assert_eq!(AsRef::<[f32; 3]>::as_ref(&p), &[1.5, -2.0, 3.25]);
+    assert_eq!(AsRef::<(f32, f32, f32)>::as_ref(&p), &(1.5, -2.0, 3.25));
+    assert_eq!(
+        std::mem::size_of::<Point3<f32>>(),
+        std::mem::size_of::<[f32; 3]>()
+    );
+
+    let p = Point2::new(4u8, 5);
+    assert_eq!(Point2::from(<[u8; 2]>::from(p)), p);
+    assert_eq!(Point2::from(<(u8, u8)>::from(p)), p);
+
+    let p = Point1::new(-7i64);
+    assert_eq!(Point1::from(<[i64; 1]>::from(p)), p);
+    assert_eq!(Point1::from(<(i64,)>::from(p)), p);
+    assert_eq!(AsRef::<[i64; 1]>::as_ref(&p), &[-7]);
+}
+
+#[test]
+fn test_scale_by() {
+    let p = Point3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(
+        p.scale_by(Vector3::new(2.0, 0.5, 1.0)),
+        Point3::new(2.0, 1.0, 3.0)
+    );
+    assert_eq!(
+        p.scale_by(Vector3::new(2.0, 0.5, 1.0)),
+        Matrix4::from_nonuniform_scale(2.0, 0.5, 1.0).transform_point(p)
+    );
+    assert_eq!(
+        Point3::new(1, -2, 3).scale_by(Vector3::new(3, 3, 0)),
+        Point3::new(3, -6, 0)
+    );
+}
+
+#[test]
+fn test_rotate_around() {
+    let pivot = Point2::new(1.0f64, 1.0);
+    let p = Point2::new(3.0f64, 1.0);
+    assert_ulps_eq!(p.rotate_around(Deg(90.0), pivot), Point2::new(1.0, 3.0));
+    assert_ulps_eq!(p.rotate_around(Rad(0.0), pivot), p);
+    assert_ulps_eq!(pivot.rotate_around(Deg(37.0), pivot), pivot);
+}
+
+#[test]
+fn test_lerp() {
+    let a = Point3::new(1.0f64, -2.0, 4.0);
+    let b = Point3::new(3.0f64, 6.0, -4.0);
+    assert_ulps_eq!(a.lerp(b, 0.0), a);
+    assert_ulps_eq!(a.lerp(b, 0.5), Point3::new(2.0, 2.0, 0.0));
+    assert_ulps_eq!(a.lerp(b, 0.5), a.midpoint(b));
+    assert_ulps_eq!(a.lerp(b, 1.0), b);
+}
+
 #[test]
 fn test_cast() {
     assert_ulps_eq!(Point1::new(0.9f64).cast().unwrap(), Point1::new(0.9f32));
@@ -93,3 +179,106 @@ fn test_cast() {
         Point3::new(1.0f32, 2.4, -3.13)
     );
 }
+
+#[test]
+fn test_float_conversions() {
+    let p = Point3::new(0.1f64, 1.0 / 3.0, -16777217.0);
+    assert_eq!(p.to_f32(), Point3::new(0.1f32, 1.0 / 3.0, -16777216.0));
+    assert_eq!(
+        Point2::new(0.5f32, -2.0).to_f64(),
+        Point2::new(0.5f64, -2.0)
+    );
+}
+
+#[test]
+fn test_index_axis() {
+    let mut p = Point2::new(1.0f64, 2.0);
+    assert_eq!(p[Axis2::X], 1.0);
+    assert_eq!(p[Axis2::Y], 2.0);
+
+    p[Axis2::X] = 5.0;
+    assert_eq!(p, Point2::new(5.0, 2.0));
+    assert_eq!(Point3::new(1, 2, 3)[Axis3::Z], 3);
+}
+
+#[test]
+fn test_ord() {
+    let mut points = vec![
+        Point2::new(1, 2),
+        Point2::new(0, 5),
+        Point2::new(1, -1),
+        Point2::new(-3, 7),
+        Point2::new(0, 5),
+    ];
+    points.sort();
+    assert_eq!(
+        points,
+        vec![
+            Point2::new(-3, 7),
+            Point2::new(0, 5),
+            Point2::new(0, 5),
+            Point2::new(1, -1),
+            Point2::new(1, 2),
+        ]
+    );
+    points.dedup();
+    assert_eq!(points.len(), 4);
+
+    assert!(Point3::new(1u8, 2, 3) < Point3::new(1, 3, 0));
+    assert_eq!(
+        Point3::new(1, 2, 3).max(Point3::new(1, 2, 4)),
+        Point3::new(1, 2, 4)
+    );
+}
+
+#[test]
+fn test_partial_min_max() {
+    let points = [
+        Point3::new(1.0f32, 5.0, -2.0),
+        Point3::new(f32::NAN, -1.0, 0.0),
+        Point3::new(-4.0, 2.0, f32::NAN),
+    ];
+    let (min, max) = points
+        .iter()
+        .skip(1)
+        .fold((points[0], points[0]), |(min, max), &p| {
+            (min.partial_min(p), max.partial_max(p))
+        });
+    assert_eq!(min, Point3::new(-4.0, -1.0, -2.0));
+    assert_eq!(max, Point3::new(1.0, 5.0, 0.0));
+}
+
+#[test]
+fn test_try_from_slice() {
+    use cgmath::conv::SliceLengthError;
+    use std::convert::TryFrom;
+
+    let data = [1i32, -2, 3];
+    assert_eq!(Point3::try_from(&data[..]), Ok(Point3::new(1, -2, 3)));
+    assert_eq!(Point2::try_from(&data[1..]), Ok(Point2::new(-2, 3)));
+    assert_eq!(
+        Point2::try_from(&data[..]),
+        Err(SliceLengthError {
+            expected: 2,
+            found: 3
+        })
+    );
+}
+
+#[test]
+fn test_abs_diff() {
+    let a = Point3::new(1u32, 5, 2);
+    assert_eq!(a.abs_diff(Point3::new(3, 1, 2)), Point3::new(2, 4, 0));
+    assert_eq!(a.abs_diff_scalar(2), Point3::new(1, 3, 0));
+}
+
+#[test]
+fn test_div_scalar_fast() {
+    let p = Point3::new(1.0f64, -2.0, 7.0);
+    assert_relative_eq!(
+        p.div_scalar_fast(3.0),
+        p / 3.0,
+        max_relative = 4.0 * f64::EPSILON
+    );
+    assert_eq!(p.div_scalar_fast(4.0), Point3::new(0.25, -0.5, 1.75));
+}
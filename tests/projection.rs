@@ -13,9 +13,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+extern crate approx;
 extern crate cgmath;
 
-use cgmath::{ortho, Matrix4, Vector4};
+use cgmath::{
+    ortho, ortho_symmetric, perspective, perspective_infinite, unproject, Deg, Matrix4, Point3,
+    SquareMatrix, Vector3, Vector4,
+};
 
 #[test]
 fn test_ortho_scale() {
@@ -66,3 +71,82 @@ fn test_ortho_translate() {
     let orig = o * vec_orig;
     assert_eq!(orig, Vector4::new(1., 1., 1., 1.));
 }
+
+#[test]
+fn test_perspective_infinite() {
+    let p: Matrix4<f64> = perspective_infinite(Deg(60.0), 1.5, 0.1);
+    let depth = |z: f64| {
+        let clip = p * Vector4::new(0.0, 0.0, z, 1.0);
+        clip.z / clip.w
+    };
+
+    // the near plane maps to -1
+    assert_ulps_eq!(depth(-0.1), -1.0);
+
+    // depth increases towards +1 without reaching it
+    let mut last = depth(-0.1);
+    for &z in &[-1.0, -10.0, -1000.0, -1.0e6] {
+        let d = depth(z);
+        assert!(d > last && d < 1.0);
+        last = d;
+    }
+    assert!(1.0 - last < 1.0e-6);
+}
+
+#[test]
+fn test_perspective_infinite_limit() {
+    let p: Matrix4<f64> = perspective(Deg(60.0), 1.5, 0.1, 1.0e12);
+    let q: Matrix4<f64> = perspective_infinite(Deg(60.0), 1.5, 0.1);
+    assert_relative_eq!(p, q, epsilon = 1.0e-9);
+}
+
+#[test]
+fn test_unproject() {
+    let proj = perspective(Deg(60.0f64), 1.5, 0.1, 100.0);
+    let view = Matrix4::look_at_rh(
+        Point3::new(1.0, 2.0, 5.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Vector3::unit_y(),
+    );
+    let view_proj = proj * view;
+    let inverse = view_proj.invert().unwrap();
+
+    for &world in &[
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(0.5, -0.3, 1.0),
+        Point3::new(-2.0, 1.0, -10.0),
+    ] {
+        let ndc = view_proj.transform_point(world);
+        assert!(ndc.x.abs() <= 1.0 && ndc.y.abs() <= 1.0 && ndc.z.abs() <= 1.0);
+        assert_relative_eq!(unproject(ndc, &inverse), world, epsilon = 1e-9);
+    }
+
+    // the centre of the near plane is in front of the eye, along the view direction
+    let near = unproject(Point3::new(0.0, 0.0, -1.0), &inverse);
+    let eye = Point3::new(1.0, 2.0, 5.0);
+    assert_relative_eq!(near.distance(eye), 0.1, epsilon = 1e-9);
+
+    let ortho = ortho(-2.0f64, 2.0, -1.0, 1.0, 0.0, 10.0);
+    let inverse = ortho.invert().unwrap();
+    assert_relative_eq!(
+        unproject(Point3::new(1.0, -1.0, 1.0), &inverse),
+        Point3::new(2.0, -1.0, -10.0),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_ortho_symmetric() {
+    let (w, h) = (800.0f64, 600.0);
+    let o = ortho_symmetric(w, h, 0.1, 100.0);
+    assert_ulps_eq!(o, ortho(-w / 2.0, w / 2.0, -h / 2.0, h / 2.0, 0.1, 100.0));
+
+    for &(x, y) in &[(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+        let corner = Vector4::new(x * w / 2.0, y * h / 2.0, -0.1, 1.0);
+        assert_ulps_eq!(o * corner, Vector4::new(x, y, -1.0, 1.0));
+    }
+    assert_ulps_eq!(
+        o * Vector4::new(0.0, 0.0, -100.0, 1.0),
+        Vector4::new(0.0, 0.0, 1.0, 1.0)
+    );
+}
@@ -65,6 +65,16 @@ mod operators {
         );
     }
 
+    #[test]
+    fn test_mul_assign() {
+        let q = Quaternion::from(Euler::new(Deg(30.0f64), Deg(-45.0), Deg(10.0)));
+        let r = Quaternion::new(0.5f64, 0.5, -0.5, 0.5);
+
+        let mut p = q;
+        p *= r;
+        assert_eq!(p, q * r);
+    }
+
     #[test]
     fn test_iter_sum() {
         let q1 = Quaternion::from(Euler {
@@ -213,6 +223,20 @@ mod to_from_euler {
             z: Rad(1f32),
         });
     }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Euler::new(Rad(0.1f32), Rad(0.2), Rad(0.3));
+        let b = Euler::new(Rad(0.1f32 + 1e-8), Rad(0.2), Rad(0.3 - 1e-8));
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+
+        let c = Euler::new(Rad(0.1f32), Rad(0.2 + 1e-3), Rad(0.3));
+        assert_ulps_ne!(a, c);
+        assert_relative_ne!(a, c);
+        assert_abs_diff_eq!(a, c, epsilon = 1e-2);
+    }
 }
 
 mod from {
@@ -249,6 +273,56 @@ mod from {
         fn test_zz_maximum() {
             check_with_euler(Rad(1.0f32), Rad(1.0), Rad(3.0f32));
         }
+
+        fn check_round_trip(axis: Vector3<f64>, angle: Deg<f64>) {
+            let q = Quaternion::from_axis_angle(axis.normalize(), angle);
+            let r = Quaternion::from(Matrix3::from(q));
+            assert_ulps_eq!(r.magnitude(), 1.0);
+            // `q` and `-q` represent the same rotation
+            let r = if r.dot(q) < 0.0 { -r } else { r };
+            assert_relative_eq!(r, q, epsilon = 1e-12);
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let axes = [
+                Vector3::unit_x(),
+                Vector3::unit_y(),
+                Vector3::unit_z(),
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(-3.0, 0.5, 1.0),
+                Vector3::new(0.2, -1.0, -4.0),
+            ];
+            let angles = [0.0, 10.0, 90.0, 135.0, 179.0, 179.999, 180.0, -179.999];
+            for &axis in axes.iter() {
+                for &angle in angles.iter() {
+                    check_round_trip(axis, Deg(angle));
+                }
+            }
+        }
+
+        #[test]
+        fn test_to_rotation_matrix() {
+            let q =
+                Quaternion::from_axis_angle(Vector3::new(1.0f64, -2.0, 0.5).normalize(), Deg(70.0));
+            assert_relative_eq!(q.to_rotation_matrix(), Matrix3::from(q), epsilon = 1e-12);
+
+            // a deliberately scaled quaternion still gives an orthonormal matrix
+            for &scale in &[0.5, 0.999, 1.001, 3.0] {
+                let m = (q * scale).to_rotation_matrix();
+                assert_relative_eq!(m, Matrix3::from(q), epsilon = 1e-12);
+                assert_relative_eq!(m.transpose() * m, Matrix3::identity(), epsilon = 1e-12);
+                assert_relative_eq!(m.determinant(), 1.0, epsilon = 1e-12);
+            }
+            // whereas the fast path does not normalize
+            let m = Matrix3::from(q * 3.0);
+            assert!(!m.transpose().relative_eq(&m.invert().unwrap(), 1e-6, 1e-6));
+
+            assert_eq!(
+                Quaternion::<f64>::zero().to_rotation_matrix(),
+                Matrix3::identity()
+            );
+        }
     }
 }
 
@@ -286,6 +360,178 @@ mod arc {
         let q2 = Quaternion::from_axis_angle(Vector3::unit_z(), Rad::turn_div_4());
         assert_ulps_eq!(q, q2);
     }
+
+    #[test]
+    fn test_zero() {
+        let zero = Vector3::zero();
+        let v = vec3(1.0f32, 2.0, 3.0);
+        for &(src, dst) in &[(zero, v), (v, zero), (zero, zero)] {
+            let q = Quaternion::from_arc(src, dst, None);
+            assert!(q.is_finite());
+            assert_eq!(q, Quaternion::one());
+            assert_eq!(Quaternion::try_from_arc(src, dst, None), None);
+            assert!(Quaternion::rotation_between_axis(src, dst, Vector3::unit_y()).is_finite());
+        }
+    }
+
+    #[test]
+    fn test_tiny() {
+        let src = vec3(1e-18f32, 0.0, 0.0);
+        let dst = vec3(0.0, 1e-18f32, 0.0);
+        let q = Quaternion::try_from_arc(src, dst, None).unwrap();
+        assert_ulps_eq!(
+            q,
+            Quaternion::from_axis_angle(Vector3::unit_z(), Rad::turn_div_4())
+        );
+    }
+
+    #[test]
+    fn test_opposite_preferred_axis() {
+        let v = Vector3::unit_x();
+        let q: Quaternion<f32> = Quaternion::rotation_between_axis(v, -v, vec3(1.0, 0.0, 2.0));
+        assert_ulps_eq!(
+            q,
+            Quaternion::from_axis_angle(Vector3::unit_z(), Rad::turn_div_2())
+        );
+        assert_ulps_eq!(q.rotate_vector(v), -v);
+    }
+
+    #[test]
+    fn test_opposite_preferred_axis_parallel() {
+        let v = Vector3::unit_x();
+        let q: Quaternion<f32> = Quaternion::rotation_between_axis(v, -v, v);
+        assert_ulps_eq!(q, Quaternion::from_arc(v, -v, None));
+    }
+
+    #[test]
+    fn test_preferred_axis_ignored() {
+        let q: Quaternion<f32> = Quaternion::rotation_between_axis(
+            Vector3::unit_x(),
+            Vector3::unit_y(),
+            Vector3::unit_x(),
+        );
+        assert_ulps_eq!(
+            q,
+            Quaternion::from_arc(Vector3::unit_x(), Vector3::unit_y(), None)
+        );
+    }
+}
+
+mod look_at_with {
+    use cgmath::*;
+
+    #[test]
+    fn test_maps_forward_axis() {
+        let dir = vec3(1.0f64, 2.0, -3.0);
+        let up = vec3(0.0, 1.0, 0.0);
+        let forward_axis = vec3(0.0, 0.0, -1.0);
+        let up_axis = vec3(0.0, 1.0, 0.0);
+        let q = Quaternion::look_at_with(dir, up, forward_axis, up_axis);
+        assert_relative_eq!(
+            q.rotate_vector(forward_axis),
+            dir.normalize(),
+            epsilon = 1e-12
+        );
+
+        // the local up axis stays in the plane spanned by `dir` and `up`
+        let new_up = q.rotate_vector(up_axis);
+        assert_relative_eq!(new_up.dot(dir.cross(up)), 0.0, epsilon = 1e-12);
+        assert!(new_up.dot(up) > 0.0);
+    }
+
+    #[test]
+    fn test_matches_look_at() {
+        let dir = vec3(1.0f64, 2.0, -3.0);
+        let up = vec3(0.0, 1.0, 0.0);
+        let q = Quaternion::look_at_with(dir, up, Vector3::unit_z(), Vector3::unit_y());
+        assert_relative_eq!(
+            Matrix3::from(q),
+            Matrix3::from(Quaternion::look_at(dir, up).invert()),
+            epsilon = 1e-12
+        );
+    }
+}
+
+mod euler_order {
+    use cgmath::*;
+
+    #[test]
+    fn test_xyz_matches_from() {
+        let euler = Euler::new(Deg(30.0f64), Deg(-45.0), Deg(120.0));
+        assert_ulps_eq!(
+            euler.to_quaternion(EulerOrder::Xyz),
+            Quaternion::from(euler)
+        );
+    }
+
+    #[test]
+    fn test_zyx() {
+        let euler = Euler::new(Deg(90.0f64), Deg(90.0), Deg(0.0));
+        let vec = vec3(0.0, 1.0, 0.0);
+
+        // Rx * Ry: the y rotation leaves the y axis alone, then x turns it onto z
+        let xyz = euler.to_quaternion(EulerOrder::Xyz);
+        assert_ulps_eq!(xyz * vec, vec3(0.0, 0.0, 1.0));
+
+        // Ry * Rx: x turns the y axis onto z, then y turns that onto x
+        let zyx = euler.to_quaternion(EulerOrder::Zyx);
+        assert_ulps_eq!(zyx * vec, vec3(1.0, 0.0, 0.0));
+        assert_ulps_eq!(
+            Matrix3::from(zyx),
+            Matrix3::from_euler_order(euler.x, euler.y, euler.z, EulerOrder::Zyx)
+        );
+    }
+}
+
+mod angle_to {
+    use cgmath::*;
+
+    #[test]
+    fn test_same() {
+        let q = Quaternion::from_axis_angle(vec3(0.0f64, 0.6, 0.8), Deg(75.0));
+        assert_ulps_eq!(q.angle_to(q), Rad(0.0));
+        assert_ulps_eq!(q.angle_to(-q), Rad(0.0));
+    }
+
+    #[test]
+    fn test_quarter_turn() {
+        let q = Quaternion::from_axis_angle(Vector3::unit_y(), Deg(30.0f64));
+        let r = Quaternion::from_axis_angle(Vector3::unit_y(), Deg(120.0f64));
+        assert_ulps_eq!(q.angle_to(r), Rad::turn_div_4());
+        assert_ulps_eq!(r.angle_to(q), Rad::turn_div_4());
+        assert_ulps_eq!(q.angle_to(-r), Rad::turn_div_4());
+    }
+}
+
+mod rotation_to {
+    use cgmath::*;
+
+    #[test]
+    fn test_same() {
+        let q = Quaternion::from_axis_angle(vec3(0.0f64, 0.6, 0.8), Deg(75.0));
+        assert_ulps_eq!(q.rotation_to(q), Quaternion::one());
+        assert_ulps_eq!(q.rotation_to(-q), Quaternion::one());
+    }
+
+    #[test]
+    fn test_apply() {
+        let q = Quaternion::from_axis_angle(vec3(0.0f64, 0.6, 0.8), Deg(75.0));
+        let r = Quaternion::from_axis_angle(vec3(1.0f64, 2.0, -2.0).normalize(), Deg(-40.0));
+        let delta = q.rotation_to(r);
+        assert_ulps_eq!(delta * q, r);
+        assert_ulps_eq!(q.rotation_to(-r) * q, r);
+        assert_ulps_eq!(delta.s.acos() * 2.0, q.angle_to(r).0);
+
+        let v = vec3(3.0, -1.0, 0.5);
+        assert_ulps_eq!(delta.rotate_vector(q.rotate_vector(v)), r.rotate_vector(v));
+    }
+
+    #[test]
+    fn test_shortest() {
+        let q = Quaternion::from_angle_z(Deg(10.0f64));
+        let r = Quaternion::from_angle_z(Deg(350.0f64));
+        assert_ulps_eq!(q.rotation_to(r), Quaternion::from_angle_z(Deg(-20.0)));
+    }
 }
 
 mod rotate_from_euler {
@@ -341,6 +587,25 @@ mod rotate_from_euler {
         let rot = Quaternion::from(Euler::new(Deg(0.0), Deg(90.0), Deg(90.0)));
         assert_ulps_eq!(vec3(1.0, 0.0, 0.0), rot * vec);
     }
+
+    #[test]
+    fn test_from_euler_angles() {
+        let angles = [
+            (Deg(0.0f64), Deg(0.0), Deg(0.0)),
+            (Deg(90.0), Deg(0.0), Deg(0.0)),
+            (Deg(30.0), Deg(-45.0), Deg(120.0)),
+            (Deg(-170.0), Deg(80.0), Deg(-15.0)),
+        ];
+        for &(roll, pitch, yaw) in &angles {
+            assert_eq!(
+                Quaternion::from_euler_angles(roll, pitch, yaw),
+                Quaternion::from(Euler::new(roll, pitch, yaw))
+            );
+        }
+
+        let rot = Quaternion::from_euler_angles(Rad(0.0f64), Rad(0.0), Rad::turn_div_4());
+        assert_ulps_eq!(vec3(0.0, 1.0, 0.0), rot * vec3(1.0, 0.0, 0.0));
+    }
 }
 
 mod rotate_from_axis_angle {
@@ -469,3 +734,23 @@ mod cast {
         );
     }
 }
+
+#[cfg(feature = "float-hash")]
+mod float_hash {
+    use cgmath::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_hash_map_key() {
+        let q = Quaternion::new(0.5f32, 0.5, -0.5, 0.5);
+        let mut cache = HashMap::new();
+        cache.insert(FloatHash(q), Matrix3::from(q));
+
+        assert_eq!(cache.get(&FloatHash(q)), Some(&Matrix3::from(q)));
+        assert_eq!(
+            cache.get(&FloatHash(Quaternion::new(0.5, 0.5, -0.5, 0.5001))),
+            None
+        );
+        assert_eq!(cache.get(&FloatHash(-q)), None);
+    }
+}
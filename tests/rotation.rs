@@ -45,3 +45,68 @@ fn test_invert_basis3() {
     let a: &Matrix3<_> = a.as_ref();
     assert!(a.is_identity());
 }
+
+#[test]
+fn test_basis2_from_direction() {
+    let rot: Basis2<f64> = Basis2::from_direction(Vector2::new(0.0, 1.0));
+    assert_ulps_eq!(rot.rotate_vector(Vector2::unit_x()), Vector2::unit_y());
+
+    let dir = Vector2::new(-3.0f64, -4.0);
+    let rot = Basis2::from_direction(dir);
+    assert_ulps_eq!(rot.rotate_vector(Vector2::unit_x()), dir.normalize());
+
+    let rot = Basis2::from_direction(Vector2::new(0.0f64, 0.0));
+    assert_eq!(rot, Basis2::one());
+}
+
+#[test]
+fn test_identity() {
+    fn check<R: Rotation<Space = Point3<f64>>>() {
+        let v = Vector3::new(1.0, -2.0, 3.0);
+        assert_eq!(R::identity().rotate_vector(v), v);
+        assert!(ulps_eq!(R::identity(), R::one()));
+    }
+
+    check::<Quaternion<f64>>();
+    check::<Basis3<f64>>();
+
+    let v = Vector2::new(1.0f64, -2.0);
+    assert_eq!(Basis2::identity().rotate_vector(v), v);
+}
+
+#[test]
+fn test_basis2_angle() {
+    for &deg in &[0.0f64, 30.0, 90.0, 179.0, -45.0, -170.0] {
+        let b: Basis2<f64> = Rotation2::from_angle(Deg(deg));
+        assert_relative_eq!(Deg::from(b.angle()), Deg(deg), epsilon = 1e-10);
+    }
+
+    // angles outside of `[-180, 180]` are wrapped
+    let b: Basis2<f64> = Rotation2::from_angle(Deg(270.0));
+    assert_relative_eq!(Deg::from(b.angle()), Deg(-90.0), epsilon = 1e-10);
+}
+
+#[test]
+fn test_basis3_to_axis_angle() {
+    let axis = Vector3::new(1.0f64, -2.0, 0.5).normalize();
+    for &deg in &[10.0f64, 90.0, 135.0, 179.0] {
+        let b = Basis3::from_axis_angle(axis, Deg(deg));
+        let (a, angle) = b.to_axis_angle();
+        assert_relative_eq!(a, axis, epsilon = 1e-10);
+        assert_relative_eq!(Deg::from(angle), Deg(deg), epsilon = 1e-10);
+    }
+
+    // a negative angle flips the axis instead
+    let (a, angle) = Basis3::from_axis_angle(axis, Deg(-60.0)).to_axis_angle();
+    assert_relative_eq!(a, -axis, epsilon = 1e-10);
+    assert_relative_eq!(Deg::from(angle), Deg(60.0), epsilon = 1e-10);
+
+    let b: Basis3<f64> = rotation::a3();
+    let (a, angle) = b.to_axis_angle();
+    assert_relative_eq!(Basis3::from_axis_angle(a, angle), b, epsilon = 1e-10);
+
+    assert_eq!(
+        Basis3::<f64>::one().to_axis_angle(),
+        (Vector3::unit_x(), Rad(0.0))
+    );
+}
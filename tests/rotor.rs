@@ -0,0 +1,122 @@
+// Copyright 2013-2014 The CGMath Developers. For a full listing of the authors,
+// refer to the Cargo.toml file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate approx;
+extern crate cgmath;
+
+use cgmath::*;
+
+fn axes() -> Vec<Vector3<f64>> {
+    vec![
+        Vector3::unit_x(),
+        Vector3::unit_y(),
+        Vector3::unit_z(),
+        Vector3::new(1.0, 2.0, -3.0).normalize(),
+        Vector3::new(-0.3, 0.1, 0.9).normalize(),
+    ]
+}
+
+fn vectors() -> Vec<Vector3<f64>> {
+    vec![
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, -2.0, 0.5),
+        Vector3::new(3.0, 4.0, 5.0),
+    ]
+}
+
+#[test]
+fn test_axis_angle_matches_quaternion() {
+    for axis in axes() {
+        for &angle in &[Deg(0.0), Deg(30.0), Deg(-90.0), Deg(180.0), Deg(271.0)] {
+            let r = Rotor3::from_axis_angle(axis, angle);
+            let q = Quaternion::from_axis_angle(axis, angle);
+            assert_ulps_eq!(Quaternion::from(r), q);
+            for v in vectors() {
+                assert_relative_eq!(r.rotate_vector(v), q.rotate_vector(v), epsilon = 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_basic_rotations() {
+    let r = Rotor3::from_angle_z(Deg(90.0f64));
+    assert_relative_eq!(r * Vector3::unit_x(), Vector3::unit_y(), epsilon = 1e-15);
+    let r = Rotor3::from_angle_x(Deg(90.0f64));
+    assert_relative_eq!(r * Vector3::unit_y(), Vector3::unit_z(), epsilon = 1e-15);
+    let r = Rotor3::from_angle_y(Deg(90.0f64));
+    assert_relative_eq!(r * Vector3::unit_z(), Vector3::unit_x(), epsilon = 1e-15);
+
+    let r = Rotor3::<f64>::identity();
+    assert_eq!(r * Vector3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn test_geometric_product() {
+    let a = Rotor3::from_axis_angle(axes()[3], Deg(40.0));
+    let b = Rotor3::from_axis_angle(axes()[4], Deg(-75.0));
+    let qa = Quaternion::from(a);
+    let qb = Quaternion::from(b);
+    assert_relative_eq!(Quaternion::from(a * b), qa * qb, epsilon = 1e-15);
+
+    // the right operand is applied first
+    for v in vectors() {
+        assert_relative_eq!((a * b) * v, a * (b * v), epsilon = 1e-12);
+    }
+
+    assert_relative_eq!(a * a.invert(), Rotor3::one(), epsilon = 1e-15);
+    assert_relative_eq!(a.invert(), a.reverse(), epsilon = 1e-15);
+    assert_relative_eq!([a, b].iter().product::<Rotor3<f64>>(), a * b);
+}
+
+#[test]
+fn test_from_rotation_between() {
+    for from in axes() {
+        for to in axes() {
+            let r = Rotor3::from_rotation_between(from, to);
+            assert_relative_eq!(r.magnitude(), 1.0, epsilon = 1e-12);
+            assert_relative_eq!(r * from, to, epsilon = 1e-12);
+            assert_relative_eq!(
+                r * from,
+                Quaternion::between_vectors(from, to) * from,
+                epsilon = 1e-12
+            );
+        }
+
+        // opposite vectors still give a valid half turn
+        let r = Rotor3::from_rotation_between(from, -from);
+        assert_relative_eq!(r * from, -from, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn test_conversions() {
+    let q = Quaternion::from_axis_angle(axes()[3], Deg(123.0));
+    let r = Rotor3::from(q);
+    assert_eq!(Quaternion::from(r), q);
+    assert_relative_eq!(Matrix3::from(r), Matrix3::from(q), epsilon = 1e-15);
+    assert_relative_eq!(
+        Basis3::from(r).rotate_vector(vectors()[2]),
+        q * vectors()[2],
+        epsilon = 1e-12
+    );
+
+    let euler = Euler::new(Deg(10.0), Deg(-20.0), Deg(30.0));
+    assert_relative_eq!(
+        Rotor3::from(euler),
+        Rotor3::from(Quaternion::from(euler)),
+        epsilon = 1e-15
+    );
+}
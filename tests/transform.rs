@@ -58,6 +58,119 @@ fn test_mul_one() {
     assert_ulps_eq!(one * t, t);
 }
 
+#[test]
+fn test_identity_builders() {
+    let identity: Decomposed<Vector3<f64>, Quaternion<f64>> = Decomposed::identity();
+    assert_eq!(identity, Decomposed::one());
+
+    let v = Vector3::new(1.0, -2.0, 3.0);
+    let p = Point3::new(4.0, 5.0, 6.0);
+    let t = identity.with_translation(v);
+    assert_eq!(t.transform_point(p), p + v);
+    assert_eq!(t.transform_vector(v), v);
+
+    let rot = Quaternion::from_angle_z(Deg(90.0));
+    let t = identity
+        .with_scale(2.0)
+        .with_rotation(rot)
+        .with_translation(v);
+    assert_eq!(
+        t,
+        Decomposed {
+            scale: 2.0,
+            rot,
+            disp: v,
+        }
+    );
+    assert_eq!(t.with_scale(0.5).scale, 0.5);
+    assert_eq!(t.with_scale(0.5).rot, rot);
+}
+
+#[test]
+fn test_concat_all() {
+    let a = Decomposed {
+        scale: 2.0f64,
+        rot: Quaternion::from_angle_z(Deg(90.0)),
+        disp: Vector3::new(1.0, 0.0, 0.0),
+    };
+    let b = Decomposed {
+        scale: 0.5f64,
+        rot: Quaternion::from_angle_x(Deg(30.0)),
+        disp: Vector3::new(0.0, -3.0, 2.0),
+    };
+    let c = Decomposed {
+        scale: 3.0f64,
+        rot: Quaternion::from_angle_y(Deg(-45.0)),
+        disp: Vector3::new(4.0, 5.0, 6.0),
+    };
+
+    let all = Decomposed::concat_all(&[a, b, c]);
+    assert_eq!(all, a.concat(&b).concat(&c));
+
+    // the last transform is applied first
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let expected = a.transform_point(b.transform_point(c.transform_point(p)));
+    assert_relative_eq!(all.transform_point(p), expected, epsilon = 1e-12);
+
+    assert_eq!(Decomposed::concat_all(&[a]), a);
+    let empty: [Decomposed<Vector3<f64>, Quaternion<f64>>; 0] = [];
+    assert_eq!(Decomposed::concat_all(&empty), Decomposed::one());
+}
+
+#[test]
+fn test_scale3() {
+    let scale = Scale3::from(Vector3::new(2.0f64, -1.0, 0.5));
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(scale.transform_point(p), Point3::new(2.0, -2.0, 1.5));
+    assert_eq!(
+        scale.transform_vector(Vector3::new(1.0, 1.0, 1.0)),
+        Vector3::new(2.0, -1.0, 0.5)
+    );
+    assert_eq!(Vector3::from(scale), Vector3::new(2.0, -1.0, 0.5));
+
+    // a scale followed by a translation, as a matrix
+    let t = Vector3::new(10.0, 20.0, 30.0);
+    let m = Matrix4::from_translation(t) * Matrix4::from(scale);
+    assert_eq!(m.transform_point(p), scale.transform_point(p) + t);
+    assert_eq!(
+        (Matrix4::from(scale) * Matrix4::from_translation(t)).transform_point(p),
+        scale.transform_point(p + t)
+    );
+
+    let other = Scale3(Vector3::new(3.0, 3.0, 4.0));
+    assert_eq!(scale.concat(&other), Scale3(Vector3::new(6.0, -3.0, 2.0)));
+    assert_eq!(scale * Scale3::one(), scale);
+    assert_eq!(
+        scale
+            .inverse()
+            .unwrap()
+            .transform_point(scale.transform_point(p)),
+        p
+    );
+    assert_eq!(Scale3(Vector3::new(1.0, 0.0, 1.0)).inverse(), None);
+}
+
+#[test]
+fn test_approx_eq() {
+    let t = Decomposed {
+        scale: 2.0f32,
+        rot: Quaternion::from_angle_y(Rad(0.5f32)),
+        disp: Vector3::new(1.0f32, 2.0, 3.0),
+    };
+    let u = Decomposed {
+        scale: 2.0f32 + 1e-7,
+        rot: Quaternion::from_angle_y(Rad(0.5f32 + 1e-7)),
+        disp: Vector3::new(1.0f32, 2.0 - 1e-7, 3.0),
+    };
+    assert_abs_diff_eq!(t, u);
+    assert_relative_eq!(t, u);
+    assert_ulps_eq!(t, u);
+
+    let v = Decomposed { scale: 2.1f32, ..t };
+    assert_ulps_ne!(t, v);
+    assert_relative_ne!(t, v);
+}
+
 #[test]
 fn test_invert() {
     let v = Vector3::new(1.0f64, 2.0, 3.0);
@@ -73,6 +186,34 @@ fn test_invert() {
     assert_ulps_eq!(&v, &ti.transform_vector(vt));
 }
 
+#[test]
+fn test_inverse_round_trip() {
+    let t = Decomposed {
+        scale: 3.7f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(2.0f64, -1.0, 0.5).normalize(), Deg(123.0)),
+        disp: Vector3::new(-4.0f64, 9.5, 0.25),
+    };
+    let ti = t.inverse_transform().unwrap();
+
+    let one: Decomposed<Vector3<f64>, Quaternion<f64>> = Decomposed::one();
+    assert_relative_eq!(t.concat(&ti), one, epsilon = 1e-12);
+    assert_relative_eq!(ti.concat(&t), one, epsilon = 1e-12);
+
+    // a simple linear congruential generator keeps the sample points deterministic
+    let mut seed = 12345u64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 200.0 - 100.0
+    };
+    for _ in 0..1000 {
+        let p = Point3::new(next(), next(), next());
+        assert_relative_eq!(ti.transform_point(t.transform_point(p)), p, epsilon = 1e-10);
+        assert_relative_eq!(t.transform_point(ti.transform_point(p)), p, epsilon = 1e-10);
+    }
+}
+
 #[test]
 fn test_inverse_vector() {
     let v = Vector3::new(1.0f64, 2.0, 3.0);
@@ -87,6 +228,61 @@ fn test_inverse_vector() {
     assert_ulps_eq!(v, t.transform_vector(vt));
 }
 
+/// The smallest non-negative `t` at which `ray` hits `sphere`, if any.
+fn ray_sphere_hit(ray: &Ray3<f64>, sphere: &Sphere3<f64>) -> Option<f64> {
+    let oc = ray.origin - sphere.center;
+    let a = ray.direction.magnitude2();
+    let b = oc.dot(ray.direction);
+    let c = oc.magnitude2() - sphere.radius * sphere.radius;
+    let disc = b * b - a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let t = (-b - disc.sqrt()) / a;
+    if t >= 0.0 {
+        Some(t)
+    } else {
+        Some((-b + disc.sqrt()) / a).filter(|&t| t >= 0.0)
+    }
+}
+
+#[test]
+fn test_inverse_transform_ray() {
+    let t = Decomposed {
+        scale: 2.5f64,
+        rot: Quaternion::from_axis_angle(Vector3::new(1.0f64, 2.0, -0.5).normalize(), Deg(70.0)),
+        disp: Vector3::new(3.0f64, -1.0, 4.0),
+    };
+    let unit = Sphere3::new(Point3::origin(), 1.0);
+    let world_sphere = Sphere3::new(t.transform_point(unit.center), t.scale);
+
+    let world_ray = Ray3::new(Point3::new(-6.0, 2.0, 1.0), Vector3::new(3.0, -1.0, 1.0));
+    let object_ray = t.inverse_transform_ray(&world_ray).unwrap();
+    let round_trip = t.transform_ray(&object_ray);
+    assert_relative_eq!(round_trip.origin, world_ray.origin, epsilon = 1e-12);
+    assert_relative_eq!(round_trip.direction, world_ray.direction, epsilon = 1e-12);
+
+    let world_t = ray_sphere_hit(&world_ray, &world_sphere).unwrap();
+    let object_t = ray_sphere_hit(&object_ray, &unit).unwrap();
+    assert_relative_eq!(world_t, object_t, epsilon = 1e-12);
+    assert_relative_eq!(
+        t.transform_point(object_ray.at(object_t)),
+        world_ray.at(world_t),
+        epsilon = 1e-12
+    );
+
+    // a ray that misses in world space also misses in object space
+    let miss = Ray3::new(Point3::new(-6.0, 20.0, 1.0), Vector3::new(3.0, 0.0, 1.0));
+    assert_eq!(ray_sphere_hit(&miss, &world_sphere), None);
+    assert_eq!(
+        ray_sphere_hit(&t.inverse_transform_ray(&miss).unwrap(), &unit),
+        None
+    );
+
+    let singular = Matrix4::from_nonuniform_scale(1.0f64, 0.0, 1.0);
+    assert_eq!(singular.inverse_transform_ray(&world_ray), None);
+}
+
 #[test]
 #[allow(deprecated)]
 fn test_look_at() {
@@ -149,6 +345,19 @@ fn test_look_at_rh() {
     assert_ulps_eq!(&t.transform_point(point), &view_point);
 }
 
+#[test]
+fn test_look_at_rh_to_matrix() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let center = Point3::new(-2.0f64, 0.5, -1.0);
+    let up = Vector3::new(0.0f64, 1.0, 0.0);
+    let t: Decomposed<Vector3<f64>, Quaternion<f64>> = Transform::look_at_rh(eye, center, up);
+    assert_relative_eq!(
+        Matrix4::from(t),
+        Matrix4::look_at_rh(eye, center, up),
+        epsilon = 1e-12
+    );
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_serialize() {
@@ -206,6 +206,99 @@ fn test_cross() {
     assert_eq!(a.cross(b), r);
 }
 
+#[test]
+fn test_wedge() {
+    let a = Vector3::new(1isize, 2isize, 3isize);
+    let b = Vector3::new(4isize, 5isize, 6isize);
+    assert_eq!(a.wedge(b), a.cross(b));
+    assert_eq!(b.wedge(a), -a.wedge(b));
+
+    let a = Vector2::new(1isize, 2isize);
+    let b = Vector2::new(4isize, 5isize);
+    assert_eq!(a.wedge(b), a.perp_dot(b));
+    assert_eq!(a.wedge(b), -3isize);
+}
+
+#[test]
+fn test_overflowing_ops() {
+    let a = Vector2::new(i32::MAX, 5);
+    let b = Vector2::new(1i32, 2);
+    assert_eq!(a.wrapping_add(b), Vector2::new(i32::MIN, 7));
+    assert_eq!(a.saturating_add(b), Vector2::new(i32::MAX, 7));
+    assert_eq!(a.checked_add(b), None);
+    assert_eq!(
+        Vector2::new(1i32, 5).checked_add(b),
+        Some(Vector2::new(2, 7))
+    );
+
+    let c = Vector2::new(i32::MIN, 5);
+    assert_eq!(c.wrapping_sub(b), Vector2::new(i32::MAX, 3));
+    assert_eq!(c.saturating_sub(b), Vector2::new(i32::MIN, 3));
+    assert_eq!(c.checked_sub(b), None);
+    assert_eq!(
+        Vector3::new(0u8, 10, 255).checked_sub(Vector3::new(0, 1, 255)),
+        Some(Vector3::new(0, 9, 0))
+    );
+}
+
+#[test]
+fn test_checked_element_wise() {
+    let a = Vector3::new(6i32, 7, 8);
+    assert_eq!(
+        a.checked_div_element_wise(Vector3::new(2, 3, 4)),
+        Some(Vector3::new(3, 2, 2))
+    );
+    assert_eq!(
+        a.checked_rem_element_wise(Vector3::new(2, 3, 5)),
+        Some(Vector3::new(0, 1, 3))
+    );
+    assert_eq!(a.checked_div_element_wise(Vector3::new(2, 0, 4)), None);
+    assert_eq!(a.checked_rem_element_wise(Vector3::new(2, 0, 4)), None);
+    assert_eq!(a.checked_div_element_wise(2), Some(Vector3::new(3, 3, 4)));
+    assert_eq!(a.checked_div_element_wise(0), None);
+
+    let b = Vector2::new(1.0f64, 3.0);
+    assert_eq!(
+        b.checked_div_element_wise(Vector2::new(2.0, 0.5)),
+        Some(Vector2::new(0.5, 6.0))
+    );
+    assert_eq!(b.checked_div_element_wise(Vector2::new(-0.0, 1.0)), None);
+}
+
+#[test]
+fn test_max_abs() {
+    assert_eq!(Vector3::new(1.0f64, -4.0, 3.5).max_abs(), 4.0);
+    assert_eq!(Vector4::new(0.5f32, 0.25, -0.75, 0.0).max_abs(), 0.75);
+    assert_eq!(Vector2::new(f64::NAN, -2.0).max_abs(), 2.0);
+    assert_eq!(Vector1::new(-0.0f64).max_abs(), 0.0);
+}
+
+#[test]
+fn test_abs_diff() {
+    let a = Vector3::new(1, 5, 2);
+    assert_eq!(a.abs_diff(Vector3::new(3, 1, 2)), Vector3::new(2, 4, 0));
+    assert_eq!(a.abs_diff_scalar(3), Vector3::new(2, 2, 1));
+
+    // no underflow for unsigned scalars
+    let u = Vector2::new(1u8, 200);
+    assert_eq!(u.abs_diff(Vector2::new(255, 0)), Vector2::new(254, 200));
+
+    let f = Vector2::new(-1.5f64, 2.0);
+    assert_eq!(f.abs_diff(Vector2::new(1.0, -2.0)), Vector2::new(2.5, 4.0));
+}
+
+#[test]
+fn test_homogenize() {
+    let proj: Matrix4<f64> = perspective(Deg(90.0), 1.0, 1.0, 10.0);
+    let clip = proj * Point3::new(2.0f64, -1.0, -2.0).to_homogeneous();
+    assert_relative_eq!(
+        clip.homogenize().unwrap(),
+        Vector3::new(1.0, -0.5, 1.0 / 9.0),
+        epsilon = 1e-12
+    );
+    assert_eq!(Vector4::new(1.0f64, 2.0, 3.0, 0.0).homogenize(), None);
+}
+
 #[test]
 fn test_is_perpendicular() {
     assert!(Vector2::new(1.0f64, 0.0f64).is_perpendicular(Vector2::new(0.0f64, 1.0f64)));
@@ -319,6 +412,104 @@ fn test_normalize() {
     );
 }
 
+#[test]
+fn test_normalize_to() {
+    let v = Vector3::new(1.0f64, 2.0, 2.0).normalize_to(5.0);
+    assert_ulps_eq!(v.magnitude(), 5.0);
+    assert_ulps_eq!(v, Vector3::new(5.0 / 3.0, 10.0 / 3.0, 10.0 / 3.0));
+}
+
+#[test]
+fn test_vector2_angle() {
+    assert_ulps_eq!(Vector2::from_angle(Deg(0.0f64)), Vector2::unit_x());
+    assert_ulps_eq!(Vector2::from_angle(Deg(90.0f64)), Vector2::unit_y());
+    assert_ulps_eq!(Vector2::new(-1.0f64, 0.0).to_angle(), Rad(f64::consts::PI));
+    assert_ulps_eq!(
+        Vector2::new(0.0f64, -2.0).to_angle(),
+        Rad(-f64::consts::FRAC_PI_2)
+    );
+
+    for &v in &[
+        Vector2::new(3.0f64, 4.0),
+        Vector2::new(-0.5, 2.0),
+        Vector2::new(-7.0, -1.0),
+        Vector2::new(0.1, -0.3),
+    ] {
+        assert_ulps_eq!(Vector2::from_angle(v.to_angle()), v.normalize());
+    }
+}
+
+#[test]
+fn test_to_component_vec() {
+    let v = Vector3::new(1.0f64, -2.0, 3.5).to_component_vec();
+    assert_eq!(v.len(), 3);
+    assert_eq!(v, vec![1.0, -2.0, 3.5]);
+    assert_eq!(
+        Vector4::new(4u8, 3, 2, 1).to_component_vec(),
+        vec![4, 3, 2, 1]
+    );
+
+    // `EuclideanSpace::to_vec` still converts a point to a vector
+    let p = Point3::new(1, 2, 3);
+    assert_eq!(p.to_vec().to_component_vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_from_fn() {
+    assert_eq!(Vector3::from_fn(|i| i as f64), Vector3::new(0.0, 1.0, 2.0));
+    assert_eq!(
+        Vector4::from_fn(|i| if i == 2 { 1 } else { 0 }),
+        Vector4::unit_z()
+    );
+}
+
+#[test]
+fn test_magnitude_recip() {
+    let v = Vector3::new(2.0f64, 3.0, 6.0);
+    assert_ulps_eq!(v.magnitude_recip(), 1.0 / 7.0);
+    assert_ulps_eq!(v.magnitude_recip() * v.magnitude(), 1.0);
+    assert_ulps_eq!(v * v.magnitude_recip(), v.normalize());
+
+    let v = Vector4::new(-0.3f32, 1.7, 0.01, -12.5);
+    assert_relative_eq!(v.magnitude_recip() * v.magnitude(), 1.0);
+}
+
+#[test]
+fn test_clamp_magnitude() {
+    let v = Vector2::new(30.0f64, 40.0);
+    assert_ulps_eq!(v.clamp_magnitude(5.0), Vector2::new(3.0, 4.0));
+    assert_eq!(v.clamp_magnitude(100.0), v);
+    assert_eq!(Vector2::zero().clamp_magnitude(1.0f64), Vector2::zero());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Vector3::new(1, -2, 3)), "[1, -2, 3]");
+    assert_eq!(
+        format!("{:.2}", Vector2::new(1.0f64, 2.0 / 3.0)),
+        "[1.00, 0.67]"
+    );
+    assert_eq!(format!("{:.1}", Vector1::new(0.25f32)), "[0.2]");
+    assert_eq!(
+        format!("{}", Vector4::new(0.5f64, 1.0, -1.5, 2.0)),
+        "[0.5, 1, -1.5, 2]"
+    );
+}
+
+#[test]
+fn test_is_normalized() {
+    let v = Vector3::new(1.0f64, 2.0, -3.0).normalize();
+    assert!(v.is_normalized(1e-12));
+    assert!(Vector2::<f32>::unit_y().is_normalized(0.0));
+    assert!(!(v * 1.1).is_normalized(1e-3));
+    assert!((v * 1.0001).is_normalized(1e-3));
+
+    let tiny = Vector4::new(1e-10f64, 0.0, 0.0, 0.0);
+    assert!(!tiny.is_normalized(1e-6));
+    assert!(!tiny.is_zero());
+    assert!(Vector4::<f64>::zero().is_zero());
+}
+
 #[test]
 fn test_project_on() {
     assert_ulps_eq!(
@@ -335,6 +526,181 @@ fn test_project_on() {
     );
 }
 
+#[test]
+fn test_distance() {
+    let a = Vector4::new(1.0f64, 1.0, 1.0, 1.0);
+    let b = Vector4::new(2.0f64, 2.0, 2.0, 2.0);
+    assert_ulps_eq!(a.distance(b), 2.0);
+    assert_ulps_eq!(a.distance_squared(b), 4.0);
+    assert_ulps_eq!(
+        Vector2::new(0.0f64, 0.0).distance(Vector2::new(3.0, 4.0)),
+        5.0
+    );
+}
+
+#[test]
+fn test_metric_space_distance() {
+    fn distance<T: MetricSpace<Metric = f64>>(a: T, b: T) -> f64 {
+        MetricSpace::distance(a, b)
+    }
+
+    assert_eq!(
+        distance(Vector3::new(1.0, 1.0, 7.0), Vector3::new(4.0, 5.0, 7.0)),
+        5.0
+    );
+    assert_eq!(distance(Point2::new(0.0, 0.0), Point2::new(-3.0, 4.0)), 5.0);
+    assert_eq!(
+        distance(
+            Quaternion::new(0.0, 0.0, 0.0, 0.0),
+            Quaternion::new(0.0, 3.0, 4.0, 0.0)
+        ),
+        5.0
+    );
+    // integer metrics keep the squared form
+    assert_eq!(
+        MetricSpace::distance2(Vector2::new(0, 0), Vector2::new(3, 4)),
+        25
+    );
+}
+
+#[test]
+fn test_lerp() {
+    let a = Vector3::new(1.0f64, -2.0, 4.0);
+    let b = Vector3::new(3.0f64, 6.0, -4.0);
+    assert_ulps_eq!(a.lerp(b, 0.0), a);
+    assert_ulps_eq!(a.lerp(b, 0.5), (a + b) / 2.0);
+    assert_ulps_eq!(a.lerp(b, 1.0), b);
+}
+
+#[test]
+fn test_rotate() {
+    let v = Vector2::new(1.0f64, 0.0);
+    assert_ulps_eq!(v.rotate(Deg(90.0)), Vector2::new(0.0, 1.0));
+    assert_ulps_eq!(
+        v.rotate(Rad(-f64::consts::FRAC_PI_2)),
+        Vector2::new(0.0, -1.0)
+    );
+
+    let v = Vector2::new(2.0f64, -3.0);
+    let angle = Deg(33.0);
+    assert_ulps_eq!(v.rotate(angle), Basis2::from_angle(angle).rotate_vector(v));
+}
+
+#[test]
+fn test_triple_product() {
+    let (x, y, z) = (
+        Vector3::<f64>::unit_x(),
+        Vector3::unit_y(),
+        Vector3::unit_z(),
+    );
+    assert_eq!(x.scalar_triple(y, z), 1.0);
+    assert_eq!(y.scalar_triple(x, z), -1.0);
+
+    let a = Vector3::new(1.0f64, 2.0, 3.0);
+    let b = Vector3::new(-2.0f64, 0.5, 4.0);
+    let c = Vector3::new(3.0f64, -1.0, 2.0);
+    assert_ulps_eq!(
+        a.scalar_triple(b, c),
+        Matrix3::from_cols(a, b, c).determinant()
+    );
+    // BAC-CAB identity
+    assert_ulps_eq!(a.vector_triple(b, c), b * a.dot(c) - c * a.dot(b));
+
+    let a = Vector3::new(1, 2, 3);
+    assert_eq!(a.scalar_triple(a, Vector3::new(4, 5, 6)), 0);
+}
+
+#[test]
+fn test_project_to_plane() {
+    let v = Vector3::new(3.0f64, -2.0, 5.0);
+    assert_ulps_eq!(
+        v.project_to_plane(Vector3::unit_z()),
+        Vector3::new(3.0, -2.0, 0.0)
+    );
+
+    let normal = Vector3::new(1.0f64, 1.0, 0.0);
+    let projected = v.project_to_plane(normal);
+    assert_ulps_eq!(projected, Vector3::new(2.5, -2.5, 5.0));
+    assert_ulps_eq!(projected.dot(normal), 0.0);
+    assert_ulps_eq!(projected, v.project_to_plane(normal.normalize()));
+}
+
+#[test]
+fn test_orthonormalize_against() {
+    let normal = Vector3::new(0.3f64, -1.2, 2.0);
+    for &tangent in &[
+        Vector3::new(1.0f64, 0.0, 0.0),
+        Vector3::new(0.5, 2.0, 1.0),
+        Vector3::new(-4.0, 0.1, 0.7),
+        normal * 3.0,
+        -normal,
+        Vector3::zero(),
+    ] {
+        let t = tangent.orthonormalize_against(normal);
+        assert_ulps_eq!(t.magnitude(), 1.0);
+        assert_abs_diff_eq!(t.dot(normal), 0.0, epsilon = 1e-12);
+    }
+
+    assert_ulps_eq!(
+        Vector3::new(3.0f64, 4.0, 5.0).orthonormalize_against(Vector3::unit_z()),
+        Vector3::new(0.6, 0.8, 0.0)
+    );
+    // parallel to the x axis, so the first fallback is degenerate too
+    let t = Vector3::new(2.0f64, 0.0, 0.0).orthonormalize_against(Vector3::unit_x());
+    assert_ulps_eq!(t.magnitude(), 1.0);
+    assert_ulps_eq!(t.dot(Vector3::unit_x()), 0.0);
+}
+
+#[test]
+fn test_slerp() {
+    let x = Vector3::<f64>::unit_x();
+    let y = Vector3::<f64>::unit_y();
+    let half = 0.5f64.sqrt();
+
+    assert_ulps_eq!(x.slerp(y, 0.0), x);
+    assert_ulps_eq!(x.slerp(y, 1.0), y);
+    assert_ulps_eq!(x.slerp(y, 0.5), Vector3::new(half, half, 0.0));
+    assert_ulps_eq!(x.slerp(y, 1.0 / 3.0).angle(x), Rad::from(Deg(30.0)));
+
+    // nearly parallel
+    let v = Vector3::new(1.0, 1e-4, 0.0).normalize();
+    let w = x.slerp(v, 0.5);
+    assert_ulps_eq!(w.magnitude(), 1.0);
+    assert_relative_eq!(w.angle(x), w.angle(v), epsilon = 1e-12);
+
+    // anti-parallel
+    let w = x.slerp(-x, 0.5);
+    assert_ulps_eq!(w.magnitude(), 1.0);
+    assert_ulps_eq!(w.dot(x), 0.0);
+    assert_ulps_eq!(x.slerp(-x, 1.0), -x);
+}
+
+#[test]
+fn test_nlerp() {
+    let x = Vector3::<f64>::unit_x();
+    let y = Vector3::<f64>::unit_y();
+    let half = 0.5f64.sqrt();
+
+    assert_ulps_eq!(x.nlerp(y, 0.0), x);
+    assert_ulps_eq!(x.nlerp(y, 1.0), y);
+    assert_ulps_eq!(x.nlerp(y, 0.5), Vector3::new(half, half, 0.0));
+
+    // close directions stay on the unit sphere and track slerp
+    let v = Vector3::new(1.0, 0.05, -0.02).normalize();
+    for i in 0..=10 {
+        let t = i as f64 / 10.0;
+        let w = x.nlerp(v, t);
+        assert_ulps_eq!(w.magnitude(), 1.0);
+        assert_relative_eq!(w, x.slerp(v, t), epsilon = 1e-4);
+    }
+
+    // opposite directions
+    let w = x.nlerp(-x, 0.5);
+    assert!(w.is_finite());
+    assert_ulps_eq!(w.magnitude(), 1.0);
+    assert_ulps_eq!(w.dot(x), 0.0);
+}
+
 #[test]
 fn test_cast() {
     assert_ulps_eq!(
@@ -350,3 +716,260 @@ fn test_cast() {
         Vector4::new(13.5f32, -4.6, -8.3, 2.41)
     );
 }
+
+#[test]
+fn test_float_conversions() {
+    let v = Vector3::new(0.1f64, 1.0 / 3.0, -16777217.0);
+    let w = v.to_f32();
+    assert_eq!(w, Vector3::new(0.1f32, 1.0 / 3.0, -16777216.0));
+    assert_eq!(
+        w.to_f64(),
+        Vector3::new(0.1f32 as f64, (1.0f32 / 3.0) as f64, -16777216.0)
+    );
+    assert_eq!(
+        Vector2::new(0.5f32, -2.0).to_f64(),
+        Vector2::new(0.5f64, -2.0)
+    );
+}
+
+#[test]
+fn test_rotate_axis_angle() {
+    assert_ulps_eq!(
+        Vector3::unit_x().rotate_axis_angle(Vector3::unit_z(), Deg(90.0)),
+        Vector3::unit_y()
+    );
+
+    let mut seed = 0x2545f4914f6cdd1du64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 4.0 - 2.0
+    };
+    for _ in 0..100 {
+        let axis = Vector3::new(next(), next(), next()).normalize();
+        let v = Vector3::new(next(), next(), next());
+        let angle = Rad(next() * f64::consts::PI);
+        let q = Quaternion::from_axis_angle(axis, angle);
+        assert_relative_eq!(
+            v.rotate_axis_angle(axis, angle),
+            q.rotate_vector(v),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            v.rotate_axis_angle(axis, angle),
+            Matrix3::from_axis_angle(axis, angle) * v,
+            epsilon = 1e-12
+        );
+    }
+}
+
+#[test]
+fn test_from_finite() {
+    assert_eq!(
+        Vector3::from_finite(1.0f64, -2.0, 3.0),
+        Some(Vector3::new(1.0, -2.0, 3.0))
+    );
+    assert_eq!(Vector3::from_finite(1.0f64, f64::NAN, 3.0), None);
+    assert_eq!(Vector3::from_finite(f32::INFINITY, 0.0, 0.0), None);
+    assert_eq!(Vector2::from_finite(0.0f32, f32::NEG_INFINITY), None);
+    assert_eq!(
+        Vector4::from_finite(1.0f32, 2.0, 3.0, 4.0),
+        Some(vec4(1.0, 2.0, 3.0, 4.0))
+    );
+}
+
+#[test]
+fn test_index_axis() {
+    let mut v = Vector3::new(1.0f64, 2.0, 3.0);
+    assert_eq!(v[Axis3::X], 1.0);
+    assert_eq!(v[Axis3::Y], 2.0);
+    assert_eq!(v[Axis3::Z], 3.0);
+
+    v[Axis3::Y] = -2.0;
+    assert_eq!(v, Vector3::new(1.0, -2.0, 3.0));
+    assert_eq!(Vector2::new(1, 2)[Axis2::Y], 2);
+    assert_eq!(Vector4::new(1, 2, 3, 4)[Axis4::W], 4);
+    assert_eq!(Axis3::Z.index(), 2);
+}
+
+#[test]
+fn test_approx_eq_array() {
+    // approx already compares slices element-wise, so a vector can be checked
+    // against an array literal by slicing both sides
+    let v = Vector3::new(1.0f32, 2.0, 3.0) / 3.0;
+    assert_abs_diff_eq!(v[..], [0.333_333, 0.666_667, 1.0][..], epsilon = 1e-6);
+    assert_relative_eq!(v[..], [1.0 / 3.0, 2.0 / 3.0, 1.0][..]);
+    assert_abs_diff_ne!(v[..], [0.3, 0.6, 1.0][..], epsilon = 1e-6);
+}
+
+#[test]
+fn test_to_from_bits() {
+    let v = Vector3::new(1.0f32, -2.5, 0.1);
+    let bits = v.to_bits();
+    assert_eq!(bits, Vector3::new(0x3f80_0000, 0xc020_0000, 0x3dcc_cccd));
+    assert_eq!(Vector3::from_bits(bits), v);
+
+    // the sign of zero and NaN payloads are preserved, unlike with a cast
+    let v = Vector2::new(-0.0f32, f32::from_bits(0x7fc0_1234));
+    assert_eq!(v.to_bits(), Vector2::new(0x8000_0000, 0x7fc0_1234));
+    assert_eq!(Vector2::from_bits(v.to_bits()).to_bits(), v.to_bits());
+}
+
+#[test]
+fn test_ord() {
+    let mut vs = vec![vec3(2, 0, 0), vec3(1, 9, 9), vec3(1, 9, -1), vec3(1, 0, 5)];
+    vs.sort();
+    assert_eq!(
+        vs,
+        vec![vec3(1, 0, 5), vec3(1, 9, -1), vec3(1, 9, 9), vec3(2, 0, 0)]
+    );
+}
+
+#[test]
+fn test_mul_add() {
+    assert_eq!(
+        vec3(1.0f64, 2.0, 3.0).mul_add(vec3(4.0, 5.0, 6.0), vec3(-1.0, 0.5, 2.0)),
+        vec3(3.0, 10.5, 20.0)
+    );
+
+    // (1 + e)² - (1 + 2e) is exactly e², which the unfused product rounds away
+    let e = 2.0f64.powi(-30);
+    let a = Vector2::new(1.0 + e, 1.0 + e);
+    let c = Vector2::new(-(1.0 + 2.0 * e), -(1.0 + 2.0 * e));
+    assert_eq!(a.mul_element_wise(a) + c, Vector2::new(0.0, 0.0));
+    assert_eq!(a.mul_add(a, c), Vector2::new(e * e, e * e));
+}
+
+#[test]
+fn test_homogeneous_constructors() {
+    let v = Vector3::new(1.0f64, -2.0, 3.0);
+    assert_eq!(Vector4::from_point(v), Vector4::new(1.0, -2.0, 3.0, 1.0));
+    assert_eq!(
+        Vector4::from_direction(v),
+        Vector4::new(1.0, -2.0, 3.0, 0.0)
+    );
+    assert_eq!(Vector4::from_point(v).truncate(), v);
+    assert_eq!(Vector4::from_direction(v).truncate(), v);
+
+    // only the point is moved by a translation
+    let m = Matrix4::from_translation(Vector3::new(10.0, 20.0, 30.0));
+    assert_eq!(
+        m * Vector4::from_point(v),
+        Vector4::new(11.0, 18.0, 33.0, 1.0)
+    );
+    assert_eq!(m * Vector4::from_direction(v), Vector4::from_direction(v));
+}
+
+#[test]
+fn test_partial_min_max() {
+    let a = Vector3::new(1.0f64, 5.0, -2.0);
+    let b = Vector3::new(3.0, -1.0, -2.5);
+    assert_eq!(a.partial_min(b), Vector3::new(1.0, -1.0, -2.5));
+    assert_eq!(a.partial_max(b), Vector3::new(3.0, 5.0, -2.0));
+
+    // a NaN component yields the other operand, in either position
+    let n = Vector3::new(f64::NAN, 2.0, f64::NAN);
+    assert_eq!(n.partial_min(a), Vector3::new(1.0, 2.0, -2.0));
+    assert_eq!(a.partial_min(n), Vector3::new(1.0, 2.0, -2.0));
+    assert_eq!(n.partial_max(b), Vector3::new(3.0, 2.0, -2.5));
+    assert_eq!(b.partial_max(n), Vector3::new(3.0, 2.0, -2.5));
+}
+
+#[cfg(feature = "float-hash")]
+#[test]
+fn test_float_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(t: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let a = FloatHash(Vector3::new(0.1f64, -2.5, 7.0));
+    let b = FloatHash(Vector3::new(0.1f64, -2.5, 7.0));
+    let perturbed = FloatHash(Vector3::new(0.1f64 + 1e-16, -2.5, 7.0));
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(a, perturbed);
+    assert_ne!(hash(&a), hash(&perturbed));
+
+    // signed zeros and NaNs collapse to a single key each
+    let zero = FloatHash(Vector3::new(0.0, f64::NAN, 1.0));
+    let neg_zero = FloatHash(Vector3::new(-0.0, -f64::NAN, 1.0));
+    assert_eq!(zero, neg_zero);
+    assert_eq!(hash(&zero), hash(&neg_zero));
+
+    let set: HashSet<_> = vec![a, b, perturbed, zero, neg_zero].into_iter().collect();
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_try_from_slice() {
+    use cgmath::conv::SliceLengthError;
+    use std::convert::TryFrom;
+
+    let data = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+    assert_eq!(
+        Vector3::try_from(&data[..3]),
+        Ok(Vector3::new(1.0, 2.0, 3.0))
+    );
+    assert_eq!(
+        Vector4::try_from(&data[1..]),
+        Ok(Vector4::new(2.0, 3.0, 4.0, 5.0))
+    );
+    assert_eq!(
+        Vector3::try_from(&data[..]),
+        Err(SliceLengthError {
+            expected: 3,
+            found: 5
+        })
+    );
+    assert_eq!(
+        Vector2::<f32>::try_from(&data[..0]),
+        Err(SliceLengthError {
+            expected: 2,
+            found: 0
+        })
+    );
+    assert_eq!(
+        Vector3::<f32>::try_from(&data[..2])
+            .unwrap_err()
+            .to_string(),
+        "expected a slice of length 3, found length 2"
+    );
+}
+
+#[test]
+fn test_div_scalar_fast() {
+    let mut seed = 17u64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 200.0 - 100.0
+    };
+    for _ in 0..100 {
+        let v = Vector3::new(next(), next(), next());
+        let s = next();
+        assert_relative_eq!(
+            v.div_scalar_fast(s),
+            v / s,
+            max_relative = 4.0 * f64::EPSILON
+        );
+        let v = v.cast::<f32>().unwrap();
+        let s = s as f32;
+        assert_relative_eq!(
+            v.div_scalar_fast(s),
+            v / s,
+            max_relative = 4.0 * f32::EPSILON
+        );
+    }
+    assert_eq!(
+        Vector2::new(3.0f64, -6.0).div_scalar_fast(2.0),
+        Vector2::new(1.5, -3.0)
+    );
+}